@@ -0,0 +1,38 @@
+//! Compares the SIMD accelerated `add_simd` with a scalar element by element addition.
+//!
+//! Run with `cargo bench --features simd`.
+
+use std::{hint::black_box, time::Instant};
+
+use vector::{OwnedVector, Vector};
+
+const LEN: usize = 1_000_000;
+const RUNS: u32 = 100;
+
+fn scalar_add(lhs: &OwnedVector<f64>, rhs: &OwnedVector<f64>) -> OwnedVector<f64> {
+    let vec: Vec<f64> = lhs.iter().zip(rhs).map(|(lhs, rhs)| lhs + rhs).collect();
+
+    OwnedVector::from_vec(vec, lhs.start(), lhs.end()).unwrap()
+}
+
+fn main() {
+    let lhs: OwnedVector<f64> = OwnedVector::from_num(1.5, 20, 20 + LEN - 1).unwrap();
+    let rhs: OwnedVector<f64> = OwnedVector::from_num(2.25, 20, 20 + LEN - 1).unwrap();
+
+    assert_eq!(
+        scalar_add(&lhs, &rhs).as_slice(),
+        lhs.add_simd(&rhs).unwrap().as_slice()
+    );
+
+    let now: Instant = Instant::now();
+    for _ in 0..RUNS {
+        black_box(scalar_add(black_box(&lhs), black_box(&rhs)));
+    }
+    println!("scalar add: {:?} per run", now.elapsed() / RUNS);
+
+    let now: Instant = Instant::now();
+    for _ in 0..RUNS {
+        black_box(black_box(&lhs).add_simd(black_box(&rhs)).unwrap());
+    }
+    println!("simd add:   {:?} per run", now.elapsed() / RUNS);
+}
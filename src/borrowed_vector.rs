@@ -1,9 +1,14 @@
 use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
     ops::{Index, Range, RangeInclusive},
     slice::Iter,
 };
 
-use crate::{Vector, VectorError, Vectorable, question_mark};
+use crate::{
+    Vector, VectorError, Vectorable, question_mark,
+    vector::{exclusive_offset, index_offset},
+};
 
 /// A wrapper struct around a generic slice of a [`Vec`] allowing the automatic calculation of indexing offsets.
 ///
@@ -28,9 +33,9 @@ impl<'a, V: Vectorable> BorrowedVector<'a, V> {
     /// # Errors
     ///
     /// * [`VectorError::Order`] - The order of the arguments is wrong.
-    /// `start` > `end`.
+    ///   `start` > `end`.
     /// * [`VectorError::Length`] - The expected length does not match the provided one.
-    /// `vec.len() != end - start + 1`.
+    ///   `vec.len() != end - start + 1`.
     pub const fn try_new(slice: &'a [V], start: usize, end: usize) -> Result<Self, VectorError> {
         question_mark!(VectorError::check_order(start, end));
         question_mark!(VectorError::check_len(slice.len(), start, end));
@@ -43,9 +48,9 @@ impl<'a, V: Vectorable> BorrowedVector<'a, V> {
     /// # Panics
     ///
     /// * The order of the arguments is wrong.
-    /// `start` > `end`.
-    /// *  The expected length does not match the provided one.
-    /// `vec.len() != end - start + 1`.
+    ///   `start` > `end`.
+    /// * The expected length does not match the provided one.
+    ///   `vec.len() != end - start + 1`.
     pub const fn new(slice: &'a [V], start: usize, end: usize) -> Self {
         assert!(VectorError::check_order(start, end).is_ok());
         assert!(VectorError::check_len(slice.len(), start, end).is_ok());
@@ -59,18 +64,38 @@ impl<V: Vectorable> Index<usize> for BorrowedVector<'_, V> {
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        // Underflow will wrap around and panic
-        &self.slice[index.wrapping_sub(self.start)]
+        &self.slice[index_offset(index, self.start, self.end)]
     }
 }
 
 impl<V: Vectorable> Index<RangeInclusive<usize>> for BorrowedVector<'_, V> {
     type Output = [V];
 
+    /// Returns the elements from the absolute `start` to `end` of the `range`.
+    ///
+    /// # Panics
+    ///
+    /// * A bound of the `range` is below `start` or above `end`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::panic::catch_unwind;
+    /// # use vector::{vector, Vector};
+    /// let owned = vector![5; 1, 2, 3];
+    /// let vec = owned.slice(5, 7).unwrap();
+    /// assert_eq!(&vec[6..=7], &[2, 3]);
+    ///
+    /// let error = catch_unwind(|| vec[3..=6].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 3 is below start 5");
+    ///
+    /// let error = catch_unwind(|| vec[6..=8].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 8 is above end 7");
+    /// ```
     #[inline]
     fn index(&self, range: RangeInclusive<usize>) -> &Self::Output {
-        let start: usize = range.start() - self.start;
-        let end: usize = range.end() - self.start;
+        let start: usize = index_offset(*range.start(), self.start, self.end);
+        let end: usize = index_offset(*range.end(), self.start, self.end);
 
         &self.slice[start..=end]
     }
@@ -79,10 +104,31 @@ impl<V: Vectorable> Index<RangeInclusive<usize>> for BorrowedVector<'_, V> {
 impl<V: Vectorable> Index<Range<usize>> for BorrowedVector<'_, V> {
     type Output = [V];
 
+    /// Returns the elements from the absolute `start` up to but excluding the `end` of the `range`.
+    ///
+    /// # Panics
+    ///
+    /// * A bound of the `range` is below `start` or above `end` + 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::panic::catch_unwind;
+    /// # use vector::{vector, Vector};
+    /// let owned = vector![5; 1, 2, 3];
+    /// let vec = owned.slice(5, 7).unwrap();
+    /// assert_eq!(&vec[6..8], &[2, 3]);
+    ///
+    /// let error = catch_unwind(|| vec[3..6].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 3 is below start 5");
+    ///
+    /// let error = catch_unwind(|| vec[6..9].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 9 is above end 7");
+    /// ```
     #[inline]
     fn index(&self, range: Range<usize>) -> &Self::Output {
-        let start: usize = range.start - self.start;
-        let end: usize = range.end - self.start;
+        let start: usize = exclusive_offset(range.start, self.start, self.end);
+        let end: usize = exclusive_offset(range.end, self.start, self.end);
 
         &self.slice[start..end]
     }
@@ -105,6 +151,8 @@ impl<'a, V: Vectorable> Vector<V> for BorrowedVector<'a, V> {
     }
 
     fn slice(&self, start: usize, end: usize) -> Result<BorrowedVector<'_, V>, VectorError> {
+        VectorError::check_order(start, end)?;
+
         if start >= self.start && end <= self.end {
             let internal_start: usize = start - self.start;
             let internal_end: usize = end - self.start;
@@ -143,9 +191,10 @@ impl<'a, V: Vectorable> Vector<V> for BorrowedVector<'a, V> {
     fn get_range(&self, start: usize, end: usize) -> Result<&[V], VectorError> {
         VectorError::check_order(start, end)?;
 
+        // `end` - 1 > `self.end` is equivalent to `end` > `self.end` + 1, but protects from overflow
         if start < self.start {
             Err(VectorError::Indexing { index: self.start })
-        } else if end > self.end + 1 {
+        } else if end.saturating_sub(1) > self.end {
             Err(VectorError::Indexing { index: self.end })
         } else {
             let start_offest: usize = start - self.start;
@@ -179,3 +228,39 @@ impl<'a, V: Vectorable> IntoIterator for BorrowedVector<'a, V> {
         self.slice.iter()
     }
 }
+
+impl<V: Vectorable + Display> Display for BorrowedVector<'_, V> {
+    /// Formats the range followed by each absolute index and value pair.
+    ///
+    /// For example `[5..=7] {5: 10, 6: 20, 7: 30}`.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(format, "[{}..={}] {{", self.start, self.end)?;
+
+        for (offset, value) in self.slice.iter().enumerate() {
+            if offset > 0 {
+                write!(format, ", ")?;
+            }
+            write!(format, "{}: {}", self.start + offset, value)?;
+        }
+
+        write!(format, "}}")
+    }
+}
+
+impl<V: Vectorable + PartialEq> PartialEq for BorrowedVector<'_, V> {
+    /// Two vectors are equal, if their `start`, `end` and elements are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.slice == other.slice
+    }
+}
+
+impl<V: Vectorable + Eq> Eq for BorrowedVector<'_, V> {}
+
+impl<V: Vectorable + Hash> Hash for BorrowedVector<'_, V> {
+    /// Hashes the `start`, `end` and the elements in order.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.slice.hash(state);
+    }
+}
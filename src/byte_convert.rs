@@ -0,0 +1,44 @@
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Vectorable;
+
+/// Helper trait for the little-endian byte serialization of [`OwnedVector`].
+///
+/// This trait is automatically implemented for all basic numeric types.
+/// [`isize`] and [`usize`] are excluded, because their size depends on the platform.
+pub trait ByteConvert: Vectorable {
+    /// The number of bytes of a single value.
+    const SIZE: usize;
+
+    /// Appends the little-endian bytes of the value to `bytes`.
+    fn to_le_bytes(self, bytes: &mut Vec<u8>);
+
+    /// Creates a value from its little-endian bytes.
+    ///
+    /// Returns [`None`] if `bytes` does not contain exactly [`ByteConvert::SIZE`] bytes.
+    #[must_use]
+    fn from_le_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Implements [`ByteConvert`] by forwarding to the inherent methods of the primitive.
+macro_rules! impl_byte_convert {
+    ($($t:ty),*) => {
+        $(
+            impl ByteConvert for $t {
+                const SIZE: usize = size_of::<$t>();
+
+                #[inline]
+                fn to_le_bytes(self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&<$t>::to_le_bytes(self));
+                }
+
+                #[inline]
+                fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(<$t>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_convert!(f64, f32, i128, i64, i32, i16, i8, u128, u64, u32, u16, u8);
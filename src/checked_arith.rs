@@ -0,0 +1,48 @@
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Vectorable;
+
+/// Helper trait for the overflow checked arithmetic of [`OwnedVector`].
+///
+/// This trait is automatically implemented for all integer types.
+pub trait CheckedArith: Vectorable {
+    /// Checked addition, returning [`None`] on overflow.
+    #[must_use]
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Checked subtraction, returning [`None`] on overflow.
+    #[must_use]
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Checked multiplication, returning [`None`] on overflow.
+    #[must_use]
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+/// Implements [`CheckedArith`] by forwarding to the inherent methods of the primitive.
+macro_rules! impl_checked_arith {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArith for $t {
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                #[inline]
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arith!(
+    i128, i64, i32, i16, i8, isize, u128, u64, u32, u16, u8, usize
+);
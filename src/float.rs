@@ -0,0 +1,126 @@
+use std::{
+    iter::Sum,
+    ops::{Div, Mul, Sub},
+};
+
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Signed;
+
+/// Helper trait for the floating point numerics in [`OwnedVector`].
+///
+/// This trait is automatically implemented for [`f64`] and [`f32`].
+pub trait Float:
+    Signed + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Sum
+{
+    /// The additive identity `0.0`.
+    const ZERO: Self;
+
+    /// Not a Number (`NaN`).
+    const NAN: Self;
+
+    /// Converts `value` into the nearest representable floating point number.
+    #[must_use]
+    fn from_usize(value: usize) -> Self;
+
+    /// Returns the maximum of `self` and `other`, ignoring `NaN`.
+    #[must_use]
+    fn max(self, other: Self) -> Self;
+
+    /// Returns the reciprocal `1 / self`.
+    #[must_use]
+    fn recip(self) -> Self;
+
+    /// Raises `self` to the floating point power `exp`.
+    #[must_use]
+    fn powf(self, exp: Self) -> Self;
+
+    /// Raises `self` to the integer power `exp`.
+    #[must_use]
+    fn powi(self, exp: i32) -> Self;
+
+    /// Returns the square root of `self`.
+    #[must_use]
+    fn sqrt(self) -> Self;
+
+    /// Returns `e` raised to the power of `self`.
+    #[must_use]
+    fn exp(self) -> Self;
+
+    /// Returns the natural logarithm of `self`.
+    #[must_use]
+    fn ln(self) -> Self;
+
+    /// Returns the sine of `self` in radians.
+    #[must_use]
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of `self` in radians.
+    #[must_use]
+    fn cos(self) -> Self;
+}
+
+/// Implements [`Float`] by forwarding to the inherent methods of the primitive.
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                const ZERO: Self = 0.0;
+
+                const NAN: Self = <$t>::NAN;
+
+                #[inline]
+                fn from_usize(value: usize) -> Self {
+                    value as $t
+                }
+
+                #[inline]
+                fn max(self, other: Self) -> Self {
+                    <$t>::max(self, other)
+                }
+
+                #[inline]
+                fn recip(self) -> Self {
+                    <$t>::recip(self)
+                }
+
+                #[inline]
+                fn powf(self, exp: Self) -> Self {
+                    <$t>::powf(self, exp)
+                }
+
+                #[inline]
+                fn powi(self, exp: i32) -> Self {
+                    <$t>::powi(self, exp)
+                }
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    <$t>::sqrt(self)
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    <$t>::exp(self)
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    <$t>::ln(self)
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    <$t>::sin(self)
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    <$t>::cos(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f64, f32);
@@ -0,0 +1,189 @@
+use std::{
+    ops::{Index, IndexMut},
+    slice::{Iter, IterMut},
+};
+
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::{VectorError, Vectorable};
+
+/// A wrapper struct around a generic [`Vec`] allowing the automatic calculation of signed indexing offsets.
+///
+/// In contrast to [`OwnedVector`] the `start` and `end` are [`isize`], so grids ranging from `-N` to `N` can be represented.
+///
+/// The generic value needs to implement the [`Vectorable`] trait.
+///
+/// # Example
+///
+/// ```rust
+/// # use vector::IsizeVector;
+/// let mut vec: IsizeVector<i32> = IsizeVector::from_vec(vec![1, 2, 3, 4, 5], -3).unwrap();
+/// assert_eq!(vec.end(), 1);
+/// assert_eq!(vec[-3], 1);
+///
+/// vec[-1] = 10;
+/// assert_eq!(vec.as_slice(), &[1, 2, 10, 4, 5]);
+/// assert!(vec.get(2).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IsizeVector<V: Vectorable> {
+    /// The [`Vec`]tor containing the values.
+    vector: Vec<V>,
+
+    /// The start to allow the correct index offsetting.
+    start: isize,
+
+    /// The end to allow to assertion of the correct length.
+    ///
+    /// Note that the end is included.
+    end: isize,
+}
+
+// An `IsizeVector` always holds at least one element, so an `is_empty` would be meaningless
+#[allow(clippy::len_without_is_empty)]
+impl<V: Vectorable> IsizeVector<V> {
+    /// Creates a new [`IsizeVector`] instance based on a given [`Vec`] beginning at `start`.
+    ///
+    /// The `end` is calculated from the length of `vec`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `vec` does not contain any elements.
+    /// * [`VectorError::SignedOverflow`] - The `end` does not fit into an [`isize`].
+    ///   `start` + `vec.len()` - 1 > [`isize::MAX`].
+    pub fn from_vec(vec: Vec<V>, start: isize) -> Result<Self, VectorError> {
+        if vec.is_empty() {
+            return Err(VectorError::Empty);
+        }
+
+        let end: isize = isize::try_from(vec.len() - 1)
+            .ok()
+            .and_then(|offset| start.checked_add(offset))
+            .ok_or(VectorError::SignedOverflow {
+                start,
+                len: vec.len(),
+            })?;
+
+        Ok(Self {
+            vector: vec,
+            start,
+            end,
+        })
+    }
+
+    /// Returns the `start` index of the [`IsizeVector`].
+    ///
+    /// This is the first index where an element is located.
+    #[inline]
+    #[must_use]
+    pub fn start(&self) -> isize {
+        self.start
+    }
+
+    /// Returns the `end` index of the [`IsizeVector`].
+    ///
+    /// This is the last index where an element is located.
+    #[inline]
+    #[must_use]
+    pub fn end(&self) -> isize {
+        self.end
+    }
+
+    /// Returns the length of the underlying vector.
+    ///
+    /// Per construction this is equal to `end` - `start` + 1.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vector.len()
+    }
+
+    /// Extracts a slice containing the entire vector.
+    ///
+    /// This exposes the underlying storage, where position 0 corresponds to `start`.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[V] {
+        &self.vector
+    }
+
+    /// Returns an iterator over the vector.
+    ///
+    /// The iterator yields all items from `start` to `end`.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, V> {
+        self.vector.iter()
+    }
+
+    /// Returns an [`IterMut`] of the underlying [`Vec`].
+    ///
+    /// This is simply a getter of the `iter_mut` and will not consider the offest indexing.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        self.vector.iter_mut()
+    }
+
+    /// Returns the value at the `index`th position using the offset indexing system.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::SignedIndexing`] - There is no element at `index`.
+    ///   `index` < `start` or `index` > `end`.
+    #[inline]
+    pub fn get(&self, index: isize) -> Result<V, VectorError> {
+        self.offset(index)
+            .map(|offset| self.vector[offset])
+            .ok_or(VectorError::SignedIndexing { index })
+    }
+
+    /// Translates the absolute `index` into the position inside the underlying [`Vec`].
+    #[inline]
+    fn offset(&self, index: isize) -> Option<usize> {
+        if index < self.start || index > self.end {
+            None
+        } else {
+            // Can not overflow, because `index` >= `start`
+            Some(index.abs_diff(self.start))
+        }
+    }
+}
+
+impl<V: Vectorable> Index<isize> for IsizeVector<V> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, index: isize) -> &Self::Output {
+        match self.offset(index) {
+            Some(offset) => &self.vector[offset],
+            None => panic!("{}", VectorError::SignedIndexing { index }),
+        }
+    }
+}
+
+impl<V: Vectorable> IndexMut<isize> for IsizeVector<V> {
+    #[inline]
+    fn index_mut(&mut self, index: isize) -> &mut Self::Output {
+        match self.offset(index) {
+            Some(offset) => &mut self.vector[offset],
+            None => panic!("{}", VectorError::SignedIndexing { index }),
+        }
+    }
+}
+
+impl<V: Vectorable> IntoIterator for IsizeVector<V> {
+    type Item = V;
+    type IntoIter = std::vec::IntoIter<V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.into_iter()
+    }
+}
+
+impl<'a, V: Vectorable> IntoIterator for &'a IsizeVector<V> {
+    type Item = &'a V;
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.iter()
+    }
+}
@@ -1,12 +1,36 @@
 mod borrowed_vector;
+mod byte_convert;
+mod checked_arith;
+mod float;
+mod isize_vector;
 mod macros;
+mod matrix;
 mod owned_vector;
+mod saturating_arith;
+mod signed;
+#[cfg(feature = "simd")]
+mod simd;
+mod sparse_vector;
+mod steppable;
 mod vector;
+mod vector_builder;
 mod vector_error;
 mod vectorable;
 
 pub use borrowed_vector::BorrowedVector;
+pub use byte_convert::ByteConvert;
+pub use checked_arith::CheckedArith;
+pub use float::Float;
+pub use isize_vector::IsizeVector;
+pub use matrix::Matrix;
 pub use owned_vector::OwnedVector;
-pub use vector::Vector;
+pub use saturating_arith::SaturatingArith;
+pub use signed::Signed;
+#[cfg(feature = "simd")]
+pub use simd::SimdVectorable;
+pub use sparse_vector::SparseVector;
+pub use steppable::Steppable;
+pub use vector::{Vector, zip3};
+pub use vector_builder::VectorBuilder;
 pub use vector_error::VectorError;
 pub use vectorable::Vectorable;
@@ -19,8 +19,19 @@ macro_rules! question_mark {
 ///
 /// `vector![start_index; elements...]`
 ///
+/// `vector![start_index; value; count]` repeats `value` `count` times.
+///
+/// `vector![start_index..=end_index; value]` fills the inclusive range with `value`.
+/// The `start_index` needs to be a literal, an identifier or a parenthesized expression.
+///
 /// Similar to the [`vec!`] macro.
 ///
+/// # Panics
+///
+/// * No elements are given or `count` is zero.
+/// * `start_index` + `count` - 1 does not fit into a [`usize`].
+/// * `start_index` > `end_index`.
+///
 /// # Example
 ///
 /// ```rust
@@ -31,9 +42,41 @@ macro_rules! question_mark {
 /// assert_eq!(vec[5], 5);
 /// assert_eq!(vec[6], 6);
 /// assert_eq!(vec[7], 7);
+///
+/// let zeros = vector![10; 0.0; 1024];
+/// assert_eq!(zeros.start(), 10);
+/// assert_eq!(zeros.end(), 1033);
+/// assert_eq!(zeros[500], 0.0);
+///
+/// let region = vector![100..=199; 0u32];
+/// assert_eq!(region.len(), 100);
+/// assert_eq!(region[150], 0);
+/// ```
+///
+/// ```rust,should_panic
+/// # use vector::vector;
+/// // Panics with "the end index `start + count - 1` does not fit into a `usize`"
+/// let overflow = vector![usize::MAX; 0; 2];
 /// ```
 #[macro_export]
 macro_rules! vector {
+    ($start:tt ..= $end:expr; $value:expr) => {{
+        #[allow(unused_parens)]
+        let start: usize = $start;
+
+        $crate::OwnedVector::from_num($value, start, $end).unwrap() // Panics for an inverted range
+    }};
+    ($start:expr; $value:expr; $count:expr) => {{
+        let start: usize = $start;
+        let count: usize = $count;
+        assert!(count > 0, "an `OwnedVector` can not be empty");
+
+        let end: usize = start
+            .checked_add(count - 1)
+            .expect("the end index `start + count - 1` does not fit into a `usize`");
+
+        $crate::OwnedVector::from_num($value, start, end).unwrap()
+    }};
     ($start:expr; $($element:expr),* $(,)?) => {{
         let vec = vec![$($element),*]; // Vec
 
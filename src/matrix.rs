@@ -0,0 +1,145 @@
+use crate::{BorrowedVector, OwnedVector, Vector, VectorError, Vectorable};
+
+/// A row-major two dimensional matrix allowing the automatic calculation of indexing offsets in both dimensions.
+///
+/// The generic value needs to implement the [`Vectorable`] trait.
+#[derive(Debug, Clone)]
+pub struct Matrix<V: Vectorable> {
+    /// The row-major data of all rows.
+    data: Vec<V>,
+
+    /// The index of the first row.
+    row_start: usize,
+
+    /// The index of the last row.
+    ///
+    /// Note that the end is included.
+    row_end: usize,
+
+    /// The index of the first column.
+    column_start: usize,
+
+    /// The index of the last column.
+    ///
+    /// Note that the end is included.
+    column_end: usize,
+}
+
+impl<V: Vectorable> Matrix<V> {
+    /// Creates a new [`Matrix`] from `rows` where the first row is located at `row_start`.
+    ///
+    /// The column range is given by the `start` and `end` of the rows.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `rows` does not contain any row.
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of the rows do not match.
+    /// * [`VectorError::Overflow`] - The last row index does not fit into a [`usize`].
+    pub fn from_rows(rows: &[OwnedVector<V>], row_start: usize) -> Result<Self, VectorError> {
+        let first: &OwnedVector<V> = rows.first().ok_or(VectorError::Empty)?;
+        let row_end: usize = VectorError::end_from_len(row_start, rows.len())?;
+
+        let mut data: Vec<V> = Vec::with_capacity(rows.len() * first.len());
+        for row in rows {
+            first.compatible(row)?;
+            data.extend_from_slice(row.as_slice());
+        }
+
+        Ok(Self {
+            data,
+            row_start,
+            row_end,
+            column_start: first.start(),
+            column_end: first.end(),
+        })
+    }
+
+    /// Returns the index of the first row.
+    #[inline]
+    #[must_use]
+    pub fn row_start(&self) -> usize {
+        self.row_start
+    }
+
+    /// Returns the index of the last row.
+    #[inline]
+    #[must_use]
+    pub fn row_end(&self) -> usize {
+        self.row_end
+    }
+
+    /// Returns the index of the first column.
+    #[inline]
+    #[must_use]
+    pub fn column_start(&self) -> usize {
+        self.column_start
+    }
+
+    /// Returns the index of the last column.
+    #[inline]
+    #[must_use]
+    pub fn column_end(&self) -> usize {
+        self.column_end
+    }
+
+    /// Returns the value at the position (`row`, `column`) using the offset indexing system in both dimensions.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - The `row` or `column` is out of bounds.
+    ///   The offending index is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Matrix, VectorError};
+    /// let rows = [vector![5; 1, 2, 3], vector![5; 4, 5, 6]];
+    /// let matrix: Matrix<i32> = Matrix::from_rows(&rows, 10).unwrap();
+    /// assert_eq!(matrix.get(10, 5), Ok(1));
+    /// assert_eq!(matrix.get(11, 7), Ok(6));
+    /// assert_eq!(matrix.get(12, 5), Err(VectorError::Indexing { index: 12 }));
+    /// ```
+    pub fn get(&self, row: usize, column: usize) -> Result<V, VectorError> {
+        let row_offset: usize = self.row_offset(row)?;
+
+        if column < self.column_start || column > self.column_end {
+            return Err(VectorError::Indexing { index: column });
+        }
+
+        Ok(self.data[row_offset * self.columns() + column - self.column_start])
+    }
+
+    /// Returns the row at the absolute index `row` as a [`BorrowedVector`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - The `row` is out of bounds.
+    pub fn row(&self, row: usize) -> Result<BorrowedVector<'_, V>, VectorError> {
+        let row_offset: usize = self.row_offset(row)?;
+        let columns: usize = self.columns();
+
+        let slice: &[V] = &self.data[row_offset * columns..(row_offset + 1) * columns];
+
+        BorrowedVector::try_new(slice, self.column_start, self.column_end)
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    fn columns(&self) -> usize {
+        self.column_end - self.column_start + 1
+    }
+
+    /// Translates the absolute `row` into the position of the row inside the data.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - The `row` is out of bounds.
+    #[inline]
+    fn row_offset(&self, row: usize) -> Result<usize, VectorError> {
+        if row < self.row_start || row > self.row_end {
+            Err(VectorError::Indexing { index: row })
+        } else {
+            Ok(row - self.row_start)
+        }
+    }
+}
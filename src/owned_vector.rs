@@ -1,10 +1,31 @@
 use std::{
-    fmt::Debug,
-    ops::{Index, IndexMut, Range, RangeInclusive},
+    cmp::Ordering,
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter, Write},
+    hash::{Hash, Hasher},
+    iter::{Product, Sum, repeat_n},
+    ops::{
+        Add, AddAssign, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Range, RangeInclusive,
+        Sub, SubAssign,
+    },
     slice::{Iter, IterMut},
+    str::FromStr,
 };
 
-use crate::{BorrowedVector, Vector, VectorError, Vectorable};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DVector, Scalar};
+#[cfg(feature = "ndarray")]
+use ndarray::Array1;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "simd")]
+use crate::SimdVectorable;
+use crate::{
+    BorrowedVector, ByteConvert, CheckedArith, Float, SaturatingArith, Signed, Steppable, Vector,
+    VectorError, Vectorable,
+    vector::{exclusive_offset, index_offset},
+};
 
 /// A wrapper struct around a generic [`Vec`] allowing the automatic calculation of indexing offsets.
 ///
@@ -14,74 +35,2412 @@ pub struct OwnedVector<V: Vectorable> {
     /// The [`Vec`]tor containing the values.
     vector: Vec<V>,
 
-    /// The start to allow the correct index offsetting.
-    start: usize,
+    /// The start to allow the correct index offsetting.
+    start: usize,
+
+    /// The end to allow to assertion of the correct length.
+    ///
+    /// Note that the end is included.
+    end: usize,
+
+    /// The optional values returned when indexing below `start` or above `end`.
+    ///
+    /// Vectors derived from this one do not inherit the fallbacks.
+    fallback: Option<(V, V)>,
+}
+
+impl<V: Vectorable> OwnedVector<V> {
+    /// Creates a new [`OwnedVector`] instance based on a given [`Vec`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - The order of the arguments is wrong.
+    ///   `start` > `end`.
+    /// * [`VectorError::Length`] - The expected length does not match the provided one.
+    ///   `vec.len() != end - start + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{OwnedVector, Vector, VectorError};
+    /// let vec = OwnedVector::from_vec(vec![1, 2], usize::MAX - 1, usize::MAX).unwrap();
+    /// assert_eq!(vec[usize::MAX], 2);
+    /// assert_eq!(vec.get_range(usize::MAX - 1, usize::MAX).unwrap(), &[1]);
+    ///
+    /// let error = OwnedVector::from_vec(vec![1, 2], usize::MAX, usize::MAX).unwrap_err();
+    /// assert_eq!(error, VectorError::Length { len: 2, start: usize::MAX, end: usize::MAX });
+    /// ```
+    #[inline]
+    pub fn from_vec(vec: Vec<V>, start: usize, end: usize) -> Result<Self, VectorError> {
+        // Not possible as const fn (Vec deconstruction)
+        VectorError::check_order(start, end)?;
+        VectorError::check_len(vec.len(), start, end)?;
+
+        Ok(Self {
+            vector: vec,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Creates a new [`OwnedVector`] instance based on a given [`Vec`] with fallback values.
+    ///
+    /// Indexing below `start` returns `fallback_start` and indexing above `end` returns `fallback_end` instead of panicking.
+    /// This is useful for boundary conditions.
+    /// The fallbacks are only used by the [`Index`] implementation.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - The order of the arguments is wrong.
+    ///   `start` > `end`.
+    /// * [`VectorError::Length`] - The expected length does not match the provided one.
+    ///   `vec.len() != end - start + 1`.
+    pub fn from_vec_with_fallback(
+        vec: Vec<V>,
+        start: usize,
+        end: usize,
+        fallback_start: V,
+        fallback_end: V,
+    ) -> Result<Self, VectorError> {
+        let mut vector: Self = Self::from_vec(vec, start, end)?;
+        vector.fallback = Some((fallback_start, fallback_end));
+
+        Ok(vector)
+    }
+
+    /// Converts the [`OwnedVector`] into one returning `fallback_start` and `fallback_end` for out-of-range indices.
+    ///
+    /// This bridges the offset indexing and the fallback indexing.
+    /// See [`OwnedVector::from_vec_with_fallback`] for details.
+    #[must_use]
+    pub fn into_fallback_vector(mut self, fallback_start: V, fallback_end: V) -> Self {
+        self.fallback = Some((fallback_start, fallback_end));
+        self
+    }
+
+    /// Converts the [`OwnedVector`] into one without fallback values by dropping them.
+    ///
+    /// Afterwards out-of-range indexing panics again.
+    #[must_use]
+    pub fn without_fallback(mut self) -> Self {
+        self.fallback = None;
+        self
+    }
+
+    /// Creates a new [`OwnedVector`] with a given `value` at all positions.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - `start` > `end`.
+    /// * [`VectorError::Overflow`] - The length does not fit into a [`usize`].
+    ///   `start` = 0 and `end` = [`usize::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{OwnedVector, Vector, VectorError};
+    /// let vec = OwnedVector::from_num(1, usize::MAX - 2, usize::MAX).unwrap();
+    /// assert_eq!(vec.len(), 3);
+    ///
+    /// let error = OwnedVector::from_num(1, 0, usize::MAX).unwrap_err();
+    /// assert_eq!(error, VectorError::Overflow { start: 0, len: usize::MAX });
+    /// ```
+    pub fn from_num(value: V, start: usize, end: usize) -> Result<Self, VectorError> {
+        let len: usize = VectorError::len_from_range(start, end)?;
+        let vector: Vec<V> = vec![value; len];
+
+        Ok(Self {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Creates a new [`OwnedVector`] by calling `function` with each absolute index from `start` to `end`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - `start` > `end`.
+    /// * [`VectorError::Overflow`] - The length does not fit into a [`usize`].
+    ///   `start` = 0 and `end` = [`usize::MAX`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{OwnedVector, Vector, VectorError};
+    /// let vec: OwnedVector<usize> = OwnedVector::from_fn(3, 5, |index| index * 2).unwrap();
+    /// assert_eq!(vec.as_slice(), &[6, 8, 10]);
+    ///
+    /// let error = OwnedVector::from_fn(0, usize::MAX, |index| index).unwrap_err();
+    /// assert_eq!(error, VectorError::Overflow { start: 0, len: usize::MAX });
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> V>(
+        start: usize,
+        end: usize,
+        function: F,
+    ) -> Result<Self, VectorError> {
+        VectorError::len_from_range(start, end)?;
+
+        let vector: Vec<V> = (start..=end).map(function).collect();
+
+        Ok(Self {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Creates a new dense [`OwnedVector`] from a sparse `map` keyed by absolute index.
+    ///
+    /// All indices from `start` to `end` missing in the `map` are set to `fill`.
+    /// Entries of the `map` outside of `start` and `end` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - `start` > `end`.
+    /// * [`VectorError::Overflow`] - The length does not fit into a [`usize`].
+    ///   `start` = 0 and `end` = [`usize::MAX`].
+    pub fn from_sparse(
+        map: &HashMap<usize, V>,
+        start: usize,
+        end: usize,
+        fill: V,
+    ) -> Result<Self, VectorError> {
+        Self::from_fn(start, end, |index| map.get(&index).copied().unwrap_or(fill))
+    }
+
+    /// Creates a new [`OwnedVector`] from a CSV string created by [`OwnedVector::to_csv`].
+    ///
+    /// The first line must be the `index,value` header.
+    /// The `start` is taken from the first row, the `end` from the last one.
+    /// Empty lines are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - The header is missing, a row is malformed or the indices are not contiguous and ascending.
+    /// * [`VectorError::Empty`] - There are no rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, VectorError};
+    /// let vec = vector![3; 1.5, -2.0];
+    /// assert_eq!(OwnedVector::from_csv(&vec.to_csv()), Ok(vec));
+    ///
+    /// let error = OwnedVector::<i32>::from_csv("index,value\n3,1\n5,2").unwrap_err();
+    /// assert_eq!(error, VectorError::Argument { name: "csv" });
+    /// ```
+    pub fn from_csv(csv: &str) -> Result<Self, VectorError>
+    where
+        V: FromStr,
+    {
+        const ERROR: VectorError = VectorError::Argument { name: "csv" };
+
+        let mut lines = csv.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some("index,value") {
+            return Err(ERROR);
+        }
+
+        let mut start: Option<usize> = None;
+        let mut vector: Vec<V> = Vec::new();
+        for line in lines {
+            let (index, value): (&str, &str) = line.split_once(',').ok_or(ERROR)?;
+            let index: usize = index.trim().parse().map_err(|_| ERROR)?;
+            let value: V = value.trim().parse().map_err(|_| ERROR)?;
+
+            let start: usize = *start.get_or_insert(index);
+            if start.checked_add(vector.len()) != Some(index) {
+                return Err(ERROR);
+            }
+            vector.push(value);
+        }
+
+        let start: usize = start.ok_or(VectorError::Empty)?;
+        let end: usize = VectorError::end_from_len(start, vector.len())?;
+
+        Ok(Self {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Joins consecutive [`OwnedVector`]s into a single one spanning all of their ranges.
+    ///
+    /// Each vector needs to start exactly one index past the `end` of the previous one.
+    /// The total capacity is reserved upfront.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `vectors` does not contain any vector.
+    /// * [`VectorError::Indexing`] - A vector does not start directly after the previous one.
+    ///   The offending `start` is reported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, Vector, VectorError};
+    /// let vec = OwnedVector::concat_all(vec![vector![3; 1, 2], vector![5; 3], vector![6; 4, 5]]).unwrap();
+    /// assert_eq!((vec.start(), vec.end()), (3, 7));
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+    ///
+    /// let error = OwnedVector::concat_all(vec![vector![3; 1, 2], vector![6; 3]]).unwrap_err();
+    /// assert_eq!(error, VectorError::Indexing { index: 6 });
+    /// ```
+    pub fn concat_all(vectors: Vec<OwnedVector<V>>) -> Result<Self, VectorError> {
+        let first: &OwnedVector<V> = vectors.first().ok_or(VectorError::Empty)?;
+        let start: usize = first.start;
+        let end: usize = vectors.last().map_or(first.end, |last| last.end);
+
+        for pair in vectors.windows(2) {
+            if pair[0].end.checked_add(1) != Some(pair[1].start) {
+                return Err(VectorError::Indexing {
+                    index: pair[1].start,
+                });
+            }
+        }
+
+        let mut vector: Vec<V> = Vec::with_capacity(vectors.iter().map(Vector::len).sum());
+        for part in vectors {
+            vector.extend(part.vector);
+        }
+
+        Ok(Self {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Creates a new [`OwnedVector`] beginning at `start` from run-length encoded `runs`.
+    ///
+    /// Each run is a `(value, count)` pair repeating `value` `count` times.
+    /// The `end` is calculated from the total run length.
+    /// This is the counterpart of [`OwnedVector::rle_encode`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - The total run length is zero.
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    pub fn rle_decode(runs: &[(V, usize)], start: usize) -> Result<Self, VectorError> {
+        let len: usize = runs
+            .iter()
+            .try_fold(0usize, |len, &(_, count)| len.checked_add(count))
+            .ok_or(VectorError::Overflow {
+                start,
+                len: usize::MAX,
+            })?;
+        let end: usize = VectorError::end_from_len(start, len)?;
+
+        let mut vector: Vec<V> = Vec::with_capacity(len);
+        for &(value, count) in runs {
+            vector.extend(repeat_n(value, count));
+        }
+
+        Ok(Self {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Creates a new [`OwnedVector`] from an array beginning at the given `start`.
+    ///
+    /// As the length is known at compile time, no length validation is necessary.
+    /// Empty arrays are rejected at compile time.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    ///   `start` + `N` - 1 > [`usize::MAX`].
+    pub fn from_array_start<const N: usize>(
+        array: [V; N],
+        start: usize,
+    ) -> Result<Self, VectorError> {
+        const { assert!(N > 0, "an `OwnedVector` can not be empty") };
+
+        let end: usize = VectorError::end_from_len(start, N)?;
+
+        Ok(Self {
+            vector: Vec::from(array),
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Returns an [`IterMut`] of the underlying [`Vec`].
+    ///
+    /// This is simply a getter of the `iter_mut` and will not consider the offest indexing.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        self.vector.iter_mut()
+    }
+
+    /// Extracts a mutable slice containing the entire vector.
+    ///
+    /// This exposes the underlying storage, where position 0 corresponds to `start`.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [V] {
+        &mut self.vector
+    }
+
+    /// Returns mutable references to the values at all `indices` using the offset indexing system.
+    ///
+    /// The references are returned in the order of `indices`.
+    /// The runtime only depends on the number of `indices` and not on the length of the vector.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - The first index outside of the vector.
+    ///   `index` < `start` or `index` > `end`.
+    /// * [`VectorError::Argument`] - `indices` contains an index more than once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector, VectorError};
+    /// let mut vec = vector![5; 1, 2, 3, 4];
+    /// if let Ok(mut values) = vec.get_many_mut(&[8, 5]) {
+    ///     *values[0] = 40;
+    ///     *values[1] = 10;
+    /// }
+    /// assert_eq!(vec.as_slice(), &[10, 2, 3, 40]);
+    ///
+    /// let error = vec.get_many_mut(&[6, 7, 6]).unwrap_err();
+    /// assert_eq!(error, VectorError::Argument { name: "indices" });
+    /// ```
+    pub fn get_many_mut(&mut self, indices: &[usize]) -> Result<Vec<&mut V>, VectorError> {
+        for &index in indices {
+            if index < self.start || index > self.end {
+                return Err(VectorError::Indexing { index });
+            }
+        }
+
+        // The positions inside of `indices` sorted by the index they refer to
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_unstable_by_key(|&position| indices[position]);
+        if order
+            .windows(2)
+            .any(|pair| indices[pair[0]] == indices[pair[1]])
+        {
+            return Err(VectorError::Argument { name: "indices" });
+        }
+
+        let mut references: Vec<Option<&mut V>> = (0..indices.len()).map(|_| None).collect();
+        let mut rest: &mut [V] = &mut self.vector;
+        let mut rest_offset: usize = 0;
+
+        for position in order {
+            let offset: usize = indices[position] - self.start;
+            let (_, tail) = std::mem::take(&mut rest).split_at_mut(offset - rest_offset);
+            // Safe, because the indices are unique and ascending, so `offset` is still inside of `rest`
+            let (value, tail) = tail.split_first_mut().unwrap();
+
+            references[position] = Some(value);
+            rest = tail;
+            rest_offset = offset + 1;
+        }
+
+        // Safe, because every position has been filled exactly once
+        Ok(references.into_iter().map(Option::unwrap).collect())
+    }
+
+    /// Returns the capacity of the underlying [`Vec`].
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.vector.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more elements in the underlying [`Vec`].
+    ///
+    /// This only touches the capacity.
+    /// The `start`, `end` and elements are unaffected.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vector.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the underlying [`Vec`] as much as possible.
+    ///
+    /// This only touches the capacity.
+    /// The `start`, `end` and elements are unaffected.
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.vector.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity of the underlying [`Vec`] with a lower limit of `min_capacity`.
+    ///
+    /// This only touches the capacity.
+    /// The `start`, `end` and elements are unaffected.
+    #[inline]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.vector.shrink_to(min_capacity);
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements in the underlying [`Vec`].
+    ///
+    /// This only touches the capacity.
+    /// The `start`, `end` and elements are unaffected.
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vector.reserve_exact(additional);
+    }
+
+    /// Sorts the elements in place without preserving the order of equal elements.
+    ///
+    /// The `start` and `end` are unchanged, but the element each index maps to is reordered.
+    #[inline]
+    pub fn sort_unstable(&mut self)
+    where
+        V: Ord,
+    {
+        self.vector.sort_unstable();
+    }
+
+    /// Sorts the elements in place with a comparator function without preserving the order of equal elements.
+    ///
+    /// The `start` and `end` are unchanged, but the element each index maps to is reordered.
+    /// For floating point types this allows the usage of `total_cmp`.
+    #[inline]
+    pub fn sort_unstable_by<F: FnMut(&V, &V) -> Ordering>(&mut self, function: F) {
+        self.vector.sort_unstable_by(function);
+    }
+
+    /// Retains only the elements for which `function` returns `true`.
+    ///
+    /// The `start` stays fixed and the `end` is recomputed from the new length.
+    /// Therefore, the absolute indices of all elements after a removed element shift down.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - No element is retained.
+    ///   In this case the vector is left unchanged.
+    pub fn retain<F: FnMut(&V) -> bool>(&mut self, mut function: F) -> Result<(), VectorError> {
+        let vector: Vec<V> = self
+            .vector
+            .iter()
+            .filter(|value| function(value))
+            .copied()
+            .collect();
+
+        if vector.is_empty() {
+            return Err(VectorError::Empty);
+        }
+
+        self.end = self.start + vector.len() - 1;
+        self.vector = vector;
+
+        Ok(())
+    }
+
+    /// Appends `value` and increases the `end` by one.
+    ///
+    /// The `start` stays fixed.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    ///   In this case the vector is left unchanged.
+    pub fn push(&mut self, value: V) -> Result<(), VectorError> {
+        self.end = VectorError::end_from_len(self.start, self.vector.len() + 1)?;
+        self.vector.push(value);
+
+        Ok(())
+    }
+
+    /// Removes the last element, decreases the `end` by one and returns the element.
+    ///
+    /// As a vector can not be empty, [`None`] is returned if only a single element is left.
+    pub fn pop(&mut self) -> Option<V> {
+        if self.vector.len() > 1 {
+            self.end -= 1;
+            self.vector.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Inserts `value` at the absolute `index` and increases the `end` by one.
+    ///
+    /// All elements after `index` are shifted up, so their absolute indices increase by one.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - `index` < `start` or `index` > `end` + 1.
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    pub fn insert(&mut self, index: usize, value: V) -> Result<(), VectorError> {
+        if index < self.start || index - self.start > self.vector.len() {
+            return Err(VectorError::Indexing { index });
+        }
+
+        self.end = VectorError::end_from_len(self.start, self.vector.len() + 1)?;
+        self.vector.insert(index - self.start, value);
+
+        Ok(())
+    }
+
+    /// Removes and returns the element at the absolute `index` and decreases the `end` by one.
+    ///
+    /// All elements after `index` are shifted down, so their absolute indices decrease by one.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - `index` < `start` or `index` > `end`.
+    /// * [`VectorError::Empty`] - The vector only contains a single element.
+    pub fn remove(&mut self, index: usize) -> Result<V, VectorError> {
+        if index < self.start || index > self.end {
+            return Err(VectorError::Indexing { index });
+        } else if self.vector.len() == 1 {
+            return Err(VectorError::Empty);
+        }
+
+        self.end -= 1;
+
+        Ok(self.vector.remove(index - self.start))
+    }
+
+    /// Removes and returns the element at the absolute `index` by replacing it with the last element.
+    ///
+    /// The `end` decreases by one.
+    /// In contrast to [`OwnedVector::remove`] this is O(1), but the element at `index` changes to the former last element.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - `index` < `start` or `index` > `end`.
+    /// * [`VectorError::Empty`] - The vector only contains a single element.
+    pub fn swap_remove(&mut self, index: usize) -> Result<V, VectorError> {
+        if index < self.start || index > self.end {
+            return Err(VectorError::Indexing { index });
+        } else if self.vector.len() == 1 {
+            return Err(VectorError::Empty);
+        }
+
+        self.end -= 1;
+
+        Ok(self.vector.swap_remove(index - self.start))
+    }
+
+    /// Appends all elements of `slice` and increases the `end` accordingly.
+    ///
+    /// The `start` stays fixed.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    ///   In this case the vector is left unchanged.
+    pub fn extend_from_slice(&mut self, slice: &[V]) -> Result<(), VectorError> {
+        self.end = VectorError::end_from_len(self.start, self.vector.len() + slice.len())?;
+        self.vector.extend_from_slice(slice);
+
+        Ok(())
+    }
+
+    /// Collapses consecutive equal elements into a single one and recomputes the `end`.
+    ///
+    /// The `start` stays fixed, so the absolute indices of all elements after a collapse shift down.
+    /// A vector of only equal elements collapses to a single element.
+    pub fn dedup(&mut self)
+    where
+        V: PartialEq,
+    {
+        self.vector.dedup();
+        // Safe, because at least one element remains and the vector only shrinks
+        self.end = self.start + self.vector.len() - 1;
+    }
+
+    /// Resizes the vector to `new_len` elements and recomputes the `end`.
+    ///
+    /// Growing appends `fill`, shrinking drops the last elements.
+    /// The `start` stays fixed.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `new_len` == 0.
+    ///   In this case the vector is left unchanged.
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    ///   In this case the vector is left unchanged.
+    pub fn resize(&mut self, new_len: usize, fill: V) -> Result<(), VectorError> {
+        self.end = VectorError::end_from_len(self.start, new_len)?;
+        self.vector.resize(new_len, fill);
+
+        Ok(())
+    }
+
+    /// Drops all elements after the absolute index `new_end` and sets the `end` to `new_end`.
+    ///
+    /// If `new_end` >= `end` the vector is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `new_end` < `start`, so no element would be left.
+    ///   In this case the vector is left unchanged.
+    pub fn truncate_to(&mut self, new_end: usize) -> Result<(), VectorError> {
+        if new_end < self.start {
+            return Err(VectorError::Empty);
+        }
+
+        if new_end < self.end {
+            // `new_end` - `start` + 1 is safe, because `new_end` >= `start` and `new_end` < `end`
+            self.vector.truncate(new_end - self.start + 1);
+            self.end = new_end;
+        }
+
+        Ok(())
+    }
+
+    /// Applies `function` to every element in place.
+    ///
+    /// In contrast to creating a new vector this does not allocate.
+    /// The `start` and `end` are unchanged.
+    #[inline]
+    pub fn apply<F: FnMut(V) -> V>(&mut self, mut function: F) {
+        for value in self.vector.iter_mut() {
+            *value = function(*value);
+        }
+    }
+
+    /// Multiplies every element by `factor` in place.
+    ///
+    /// In contrast to creating a new vector this does not allocate.
+    #[inline]
+    pub fn scale_mut(&mut self, factor: V)
+    where
+        V: MulAssign,
+    {
+        for value in self.vector.iter_mut() {
+            *value *= factor;
+        }
+    }
+
+    /// Divides every element by `divisor` in place.
+    ///
+    /// In contrast to creating a new vector this does not allocate.
+    #[inline]
+    pub fn unscale_mut(&mut self, divisor: V)
+    where
+        V: DivAssign,
+    {
+        for value in self.vector.iter_mut() {
+            *value /= divisor;
+        }
+    }
+
+    /// Adds `other` element by element in place.
+    ///
+    /// This is the non-panicking version of `+=`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    ///   In this case the vector is left unchanged.
+    pub fn try_add_assign(&mut self, other: &OwnedVector<V>) -> Result<(), VectorError>
+    where
+        V: AddAssign,
+    {
+        self.zip_assign(other, |lhs, rhs| *lhs += rhs)
+    }
+
+    /// Subtracts `other` element by element in place.
+    ///
+    /// This is the non-panicking version of `-=`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    ///   In this case the vector is left unchanged.
+    pub fn try_sub_assign(&mut self, other: &OwnedVector<V>) -> Result<(), VectorError>
+    where
+        V: SubAssign,
+    {
+        self.zip_assign(other, |lhs, rhs| *lhs -= rhs)
+    }
+
+    /// Multiplies `other` element by element in place.
+    ///
+    /// This is the non-panicking version of `*=`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    ///   In this case the vector is left unchanged.
+    pub fn try_mul_assign(&mut self, other: &OwnedVector<V>) -> Result<(), VectorError>
+    where
+        V: MulAssign,
+    {
+        self.zip_assign(other, |lhs, rhs| *lhs *= rhs)
+    }
+
+    /// Divides `other` element by element in place.
+    ///
+    /// This is the non-panicking version of `/=`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    ///   In this case the vector is left unchanged.
+    pub fn try_div_assign(&mut self, other: &OwnedVector<V>) -> Result<(), VectorError>
+    where
+        V: DivAssign,
+    {
+        self.zip_assign(other, |lhs, rhs| *lhs /= rhs)
+    }
+
+    /// Sums all [`OwnedVector`]s of `iter` element by element.
+    ///
+    /// This is the non-panicking version of [`Iterator::sum`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - The iterator does not yield any vector.
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of the vectors do not match.
+    pub fn try_sum<I: IntoIterator<Item = OwnedVector<V>>>(
+        iter: I,
+    ) -> Result<OwnedVector<V>, VectorError>
+    where
+        V: AddAssign,
+    {
+        Self::try_fold_vectors(iter, |lhs, rhs| *lhs += rhs)
+    }
+
+    /// Multiplies all [`OwnedVector`]s of `iter` element by element.
+    ///
+    /// This is the non-panicking version of [`Iterator::product`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - The iterator does not yield any vector.
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of the vectors do not match.
+    pub fn try_product<I: IntoIterator<Item = OwnedVector<V>>>(
+        iter: I,
+    ) -> Result<OwnedVector<V>, VectorError>
+    where
+        V: MulAssign,
+    {
+        Self::try_fold_vectors(iter, |lhs, rhs| *lhs *= rhs)
+    }
+
+    /// Folds all [`OwnedVector`]s of `iter` into the first one using `function`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - The iterator does not yield any vector.
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of the vectors do not match.
+    fn try_fold_vectors<I: IntoIterator<Item = OwnedVector<V>>, F: FnMut(&mut V, V)>(
+        iter: I,
+        mut function: F,
+    ) -> Result<OwnedVector<V>, VectorError> {
+        let mut iter = iter.into_iter();
+        let mut result: OwnedVector<V> = iter.next().ok_or(VectorError::Empty)?.without_fallback();
+
+        for vector in iter {
+            result.zip_assign(&vector, &mut function)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Combines `other` element by element into `self` with `function`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    fn zip_assign<F: FnMut(&mut V, V)>(
+        &mut self,
+        other: &OwnedVector<V>,
+        mut function: F,
+    ) -> Result<(), VectorError> {
+        self.compatible(other)?;
+
+        for (lhs, &rhs) in self.vector.iter_mut().zip(other) {
+            function(lhs, rhs);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether `other` has the same `start` and `end` regardless of its element type.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    fn compatible_range<W: Vectorable>(&self, other: &impl Vector<W>) -> Result<(), VectorError> {
+        if self.start == other.start() && self.end == other.end() {
+            Ok(())
+        } else {
+            Err(VectorError::Compatibility {
+                start_1: self.start,
+                start_2: other.start(),
+                end_1: self.end,
+                end_2: other.end(),
+            })
+        }
+    }
+
+    /// Combines two [`OwnedVector`]s element by element with `function`.
+    ///
+    /// The `start` and `end` are preserved.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub(crate) fn zip_map<W: Vectorable, U: Vectorable, F: FnMut(V, W) -> U>(
+        &self,
+        other: &impl Vector<W>,
+        mut function: F,
+    ) -> Result<OwnedVector<U>, VectorError> {
+        self.compatible_range(other)?;
+
+        Ok(OwnedVector {
+            vector: self
+                .vector
+                .iter()
+                .zip(other.iter())
+                .map(|(&lhs, &rhs)| function(lhs, rhs))
+                .collect(),
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        })
+    }
+
+    /// Returns a new [`OwnedVector`] containing `if_true` where `mask` is `true` and `if_false` otherwise.
+    ///
+    /// The `start` and `end` are preserved.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `mask` do not match.
+    pub fn select(
+        &self,
+        mask: &OwnedVector<bool>,
+        if_true: V,
+        if_false: V,
+    ) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_map(mask, |_, keep| if keep { if_true } else { if_false })
+    }
+
+    /// Returns a new [`OwnedVector`] containing only the elements where `mask` is `true`.
+    ///
+    /// The `start` is preserved and the `end` shrinks to the number of kept elements.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `mask` do not match.
+    /// * [`VectorError::Empty`] - `mask` does not contain any `true`.
+    pub fn filter_by_mask(&self, mask: &OwnedVector<bool>) -> Result<OwnedVector<V>, VectorError> {
+        self.compatible_range(mask)?;
+
+        let vector: Vec<V> = self
+            .vector
+            .iter()
+            .zip(mask)
+            .filter(|(_, keep)| **keep)
+            .map(|(&value, _)| value)
+            .collect();
+        let end: usize = VectorError::end_from_len(self.start, vector.len())?;
+
+        Ok(OwnedVector {
+            vector,
+            start: self.start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Returns a new [`OwnedVector`] containing every `step`-th element beginning with the one at `start`.
+    ///
+    /// The result is re-indexed contiguously, so it begins at `start` and the `end` reflects the reduced count.
+    /// To keep the original absolute positions use `into_indexed().step_by(step)` instead.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - `step` == 0.
+    pub fn step_by(&self, step: usize) -> Result<OwnedVector<V>, VectorError> {
+        if step == 0 {
+            return Err(VectorError::Argument { name: "step" });
+        }
+
+        let vector: Vec<V> = self.vector.iter().copied().step_by(step).collect();
+        // Safe, because at least one element is kept and the vector only shrinks
+        let end: usize = self.start + vector.len() - 1;
+
+        Ok(OwnedVector {
+            vector,
+            start: self.start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Returns a [`BorrowedVector`] of the elements in the inclusive `range` using the offset indexing system.
+    ///
+    /// In contrast to indexing with a [`RangeInclusive`] this does not panic.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - The `range` is out of bounds of the current vector.
+    /// * [`VectorError::Order`] - The start of the `range` is bigger than its end.
+    #[inline]
+    pub fn range(
+        &self,
+        range: RangeInclusive<usize>,
+    ) -> Result<BorrowedVector<'_, V>, VectorError> {
+        self.slice(*range.start(), *range.end())
+    }
+
+    /// Returns a new [`OwnedVector`] with the same elements, where `start` and `end` are shifted by `delta`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - The new `start` would be negative or does not fit into a [`usize`].
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    pub fn shifted(&self, delta: isize) -> Result<OwnedVector<V>, VectorError> {
+        let start: usize = self
+            .start
+            .checked_add_signed(delta)
+            .ok_or(VectorError::Argument { name: "delta" })?;
+
+        self.with_start(start)
+    }
+
+    /// Returns a new [`OwnedVector`] with the same elements beginning at `new_start`.
+    ///
+    /// The `end` is recalculated from the length and the original vector is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    pub fn with_start(&self, new_start: usize) -> Result<OwnedVector<V>, VectorError> {
+        let end: usize = VectorError::end_from_len(new_start, self.len())?;
+
+        Ok(OwnedVector {
+            vector: self.vector.clone(),
+            start: new_start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Returns a sparse map from absolute index to value of all elements not equal to `skip`.
+    ///
+    /// This is the counterpart of [`OwnedVector::from_sparse`].
+    #[must_use]
+    pub fn to_sparse(&self, skip: &V) -> HashMap<usize, V>
+    where
+        V: PartialEq,
+    {
+        self.vector
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| *value != skip)
+            .map(|(offset, &value)| (self.start + offset, value))
+            .collect()
+    }
+
+    /// Consumes the vector and returns an iterator over all `(index, value)` pairs.
+    ///
+    /// In contrast to [`IntoIterator`] each value is paired with its absolute index.
+    pub fn into_indexed(self) -> impl Iterator<Item = (usize, V)> {
+        let start: usize = self.start;
+
+        self.vector
+            .into_iter()
+            .enumerate()
+            .map(move |(offset, value)| (start + offset, value))
+    }
+
+    /// Returns an iterator over all values from the absolute index `start` to `end`.
+    ///
+    /// Indices outside of the vector yield `fill`.
+    /// This allows aligning vectors with different ranges without creating intermediate vectors.
+    /// If `start` > `end` the iterator is empty.
+    pub fn iter_padded(&self, start: usize, end: usize, fill: V) -> impl Iterator<Item = V> + '_ {
+        (start..=end).map(move |index| self.get_or(index, fill))
+    }
+
+    /// Returns the vector as CSV with an `index,value` header and one row per element.
+    ///
+    /// The rows use the absolute indices.
+    #[must_use]
+    pub fn to_csv(&self) -> String
+    where
+        V: Display,
+    {
+        let mut csv: String = String::from("index,value\n");
+
+        for (offset, value) in self.vector.iter().enumerate() {
+            // Writing into a `String` can not fail
+            let _ = writeln!(csv, "{},{}", self.start + offset, value);
+        }
+
+        csv
+    }
+
+    /// Returns the run-length encoding of the vector as `(value, count)` pairs.
+    ///
+    /// The `start` is not part of the encoding and needs to be passed to [`OwnedVector::rle_decode`].
+    #[must_use]
+    pub fn rle_encode(&self) -> Vec<(V, usize)>
+    where
+        V: PartialEq,
+    {
+        let mut runs: Vec<(V, usize)> = Vec::new();
+
+        for &value in &self.vector {
+            match runs.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+
+        runs
+    }
+
+    /// Applies `function` to every element and collects the results into a new [`OwnedVector`].
+    ///
+    /// The `start` and `end` are preserved.
+    #[inline]
+    pub(crate) fn map<U: Vectorable, F: FnMut(V) -> U>(&self, function: F) -> OwnedVector<U> {
+        OwnedVector {
+            vector: self.vector.iter().copied().map(function).collect(),
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        }
+    }
+
+    /// Applies the fallible `function` to every element and collects the results into a new [`OwnedVector`].
+    ///
+    /// The `start` and `end` are preserved.
+    ///
+    /// # Errors
+    ///
+    /// * The first error returned by `function`.
+    pub fn try_map<U: Vectorable, E, F: FnMut(V) -> Result<U, E>>(
+        &self,
+        function: F,
+    ) -> Result<OwnedVector<U>, E> {
+        Ok(OwnedVector {
+            vector: self
+                .vector
+                .iter()
+                .copied()
+                .map(function)
+                .collect::<Result<Vec<U>, E>>()?,
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: Default + Vectorable> OwnedVector<V> {
+    /// Creates a new [`OwnedVector`] instance based on a given `start` and `end`.
+    /// This will be filled with the [`Default`] value of the generic.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - The order of the arguments is wrong.
+    ///   `start` > `end`.
+    /// * [`VectorError::Overflow`] - The length does not fit into a [`usize`].
+    ///   `start` = 0 and `end` = [`usize::MAX`].
+    pub fn new(start: usize, end: usize) -> Result<Self, VectorError> {
+        let len: usize = VectorError::len_from_range(start, end)?;
+        let vector: Vec<V> = vec![V::default(); len];
+
+        Ok(Self {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: PartialOrd + Vectorable> OwnedVector<V> {
+    /// Clamps each element into the band spanned by the elements of `lower` and `upper` at the same index.
+    ///
+    /// The lower bound is applied first, so where `lower[i]` > `upper[i]` the element is set to `upper[i]`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `lower` or `upper` do not match.
+    pub fn clamp_between(
+        &self,
+        lower: &OwnedVector<V>,
+        upper: &OwnedVector<V>,
+    ) -> Result<OwnedVector<V>, VectorError> {
+        self.compatible(lower)?;
+        self.compatible(upper)?;
+
+        let vector: Vec<V> = self
+            .vector
+            .iter()
+            .zip(lower)
+            .zip(upper)
+            .map(|((&value, &low), &high)| {
+                let value: V = if value < low { low } else { value };
+                if value > high { high } else { value }
+            })
+            .collect();
+
+        Ok(Self {
+            vector,
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: Vectorable + Add<Output = V>> OwnedVector<V> {
+    /// Returns the cumulative sum, where the element at `index` is the sum of all elements from `start` to `index`.
+    ///
+    /// The `start` and `end` are preserved.
+    /// For integers an overflow follows the usual Rust semantics.
+    #[must_use]
+    pub fn cumsum(&self) -> OwnedVector<V> {
+        let mut total: Option<V> = None;
+
+        self.map(|value| {
+            let sum: V = match total {
+                Some(total) => total + value,
+                None => value,
+            };
+            total = Some(sum);
+            sum
+        })
+    }
+
+    /// Returns the weighted sum `sum(self[index] * weights[index])` over the shared range.
+    ///
+    /// The accumulation starts with the first product, so no [`Default`] is required.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `weights` do not match.
+    pub fn weighted_sum(&self, weights: &impl Vector<V>) -> Result<V, VectorError>
+    where
+        V: Mul<Output = V>,
+    {
+        self.compatible(weights)?;
+
+        let mut products = self
+            .vector
+            .iter()
+            .zip(weights.iter())
+            .map(|(&value, &weight)| value * weight);
+
+        // Safe, because a vector always contains at least one element
+        let first: V = products.next().unwrap();
+
+        Ok(products.fold(first, |sum, product| sum + product))
+    }
+}
+
+impl<V: Vectorable + Sub<Output = V>> OwnedVector<V> {
+    /// Returns the discrete difference, where the element at `index` is `self[index] - self[index - 1]`.
+    ///
+    /// As there is no predecessor for the first element, the result ranges from `start` + 1 to `end`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - The vector only contains a single element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![4; 1, 2, 3, 4];
+    /// let diff = vec.cumsum().diff().unwrap();
+    /// assert_eq!(diff.start(), 5);
+    /// assert_eq!(diff.as_slice(), &vec[5..=7]);
+    /// ```
+    pub fn diff(&self) -> Result<OwnedVector<V>, VectorError> {
+        if self.vector.len() < 2 {
+            return Err(VectorError::Empty);
+        }
+
+        Ok(OwnedVector {
+            vector: self
+                .pairs()
+                .map(|(_, &left, &right)| right - left)
+                .collect(),
+            start: self.start + 1,
+            end: self.end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: Vectorable + Mul<Output = V>> OwnedVector<V> {
+    /// Returns the outer product of `self` and `other`.
+    ///
+    /// The row at position `i` is `other` scaled by the element at the absolute index `self.start() + i`.
+    /// Each row keeps the `start` and `end` of `other`.
+    #[must_use]
+    pub fn outer(&self, other: &impl Vector<V>) -> Vec<OwnedVector<V>> {
+        self.vector
+            .iter()
+            .map(|&value| OwnedVector {
+                vector: other
+                    .iter()
+                    .map(|&other_value| value * other_value)
+                    .collect(),
+                start: other.start(),
+                end: other.end(),
+                fallback: None,
+            })
+            .collect()
+    }
+}
+
+impl<V: Vectorable + Default + Add<Output = V> + Mul<Output = V>> OwnedVector<V> {
+    /// Returns the discrete convolution of `self` with `kernel`.
+    ///
+    /// The result has `self.len() + kernel.len() - 1` elements.
+    /// To compose the offsets, the result starts at `self.start() + kernel.start()`.
+    /// Therefore, the element at `index` is the sum of all `self[i] * kernel[j]` with `i + j == index`.
+    ///
+    /// # Panics
+    ///
+    /// * The `end` of the result does not fit into a [`usize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![2; 1, 2, 3].convolve(&vector![1; 0, 1, 1]);
+    /// assert_eq!((vec.start(), vec.end()), (3, 7));
+    /// assert_eq!(vec.as_slice(), &[0, 1, 3, 5, 3]);
+    /// ```
+    #[must_use]
+    pub fn convolve(&self, kernel: &impl Vector<V>) -> OwnedVector<V> {
+        let start: usize = self.start + kernel.start();
+        let len: usize = self.vector.len() + kernel.len() - 1;
+        let end: usize = VectorError::end_from_len(start, len).unwrap();
+
+        let mut vector: Vec<V> = vec![V::default(); len];
+        for (self_offset, &value) in self.vector.iter().enumerate() {
+            for (kernel_offset, &weight) in kernel.iter().enumerate() {
+                let offset: usize = self_offset + kernel_offset;
+                vector[offset] = vector[offset] + value * weight;
+            }
+        }
+
+        OwnedVector {
+            vector,
+            start,
+            end,
+            fallback: None,
+        }
+    }
+}
+
+impl<V: CheckedArith> OwnedVector<V> {
+    /// Adds two [`OwnedVector`]s element by element while checking for overflows.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    /// * [`VectorError::Arithmetic`] - The addition overflowed at an element.
+    pub fn checked_add(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_checked(other, CheckedArith::checked_add)
+    }
+
+    /// Subtracts two [`OwnedVector`]s element by element while checking for overflows.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    /// * [`VectorError::Arithmetic`] - The subtraction overflowed at an element.
+    pub fn checked_sub(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_checked(other, CheckedArith::checked_sub)
+    }
+
+    /// Multiplies two [`OwnedVector`]s element by element while checking for overflows.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    /// * [`VectorError::Arithmetic`] - The multiplication overflowed at an element.
+    pub fn checked_mul(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_checked(other, CheckedArith::checked_mul)
+    }
+
+    /// Combines two [`OwnedVector`]s element by element with a checked `operation`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    /// * [`VectorError::Arithmetic`] - The `operation` returned [`None`] for an element.
+    fn zip_checked(
+        &self,
+        other: &OwnedVector<V>,
+        operation: fn(V, V) -> Option<V>,
+    ) -> Result<OwnedVector<V>, VectorError> {
+        self.compatible(other)?;
+
+        let vector: Vec<V> = self
+            .vector
+            .iter()
+            .zip(other)
+            .enumerate()
+            .map(|(offset, (&lhs, &rhs))| {
+                operation(lhs, rhs).ok_or(VectorError::Arithmetic {
+                    index: self.start + offset,
+                })
+            })
+            .collect::<Result<Vec<V>, VectorError>>()?;
+
+        Ok(OwnedVector {
+            vector,
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: Steppable> OwnedVector<V> {
+    /// Creates a new [`OwnedVector`] with the values `begin`, `begin + step`, ... up to but excluding `end`.
+    ///
+    /// The values are located at the indices beginning at `index_start`.
+    /// A descending range is created by `begin` > `end` and a negative `step`.
+    /// For integers the generation stops once the next value would overflow, as it would be beyond `end` anyway.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `begin` == `end`.
+    /// * [`VectorError::Argument`] - `begin` and `end` are not comparable, e.g. `NaN`.
+    /// * [`VectorError::Argument`] - The `step` is zero or does not point from `begin` towards `end`.
+    /// * [`VectorError::Argument`] - The `step` is too small to change `begin` or `end`.
+    ///   This can only happen for floating point types.
+    /// * [`VectorError::Overflow`] - The `end` index does not fit into a [`usize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{OwnedVector, Vector, VectorError};
+    /// let vec: OwnedVector<u8> = OwnedVector::arange(240, 255, 10, 0).unwrap();
+    /// assert_eq!(vec.as_slice(), &[240, 250]);
+    ///
+    /// let result: Result<OwnedVector<f64>, VectorError> = OwnedVector::arange(0.0, 1.0, 1e-17, 0);
+    /// assert_eq!(result, Err(VectorError::Argument { name: "step" }));
+    /// ```
+    pub fn arange(
+        begin: V,
+        end: V,
+        step: V,
+        index_start: usize,
+    ) -> Result<OwnedVector<V>, VectorError> {
+        let ascending: bool = match begin.partial_cmp(&end) {
+            Some(Ordering::Less) => true,
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => return Err(VectorError::Empty),
+            None => return Err(VectorError::Argument { name: "end" }),
+        };
+
+        // The step advancing both bounds ensures it advances every value in between
+        let advances = |value: V| value.checked_step(step) != Some(value);
+        if !(if ascending {
+            step > V::ZERO
+        } else {
+            step < V::ZERO
+        }) || !advances(begin)
+            || !advances(end)
+        {
+            return Err(VectorError::Argument { name: "step" });
+        }
+
+        let mut vector: Vec<V> = vec![begin];
+        let mut value: V = begin;
+        while let Some(next) = value.checked_step(step) {
+            if (ascending && next >= end) || (!ascending && next <= end) {
+                break;
+            }
+            vector.push(next);
+            value = next;
+        }
+
+        let end: usize = VectorError::end_from_len(index_start, vector.len())?;
+
+        Ok(OwnedVector {
+            vector,
+            start: index_start,
+            end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: SaturatingArith> OwnedVector<V> {
+    /// Adds two [`OwnedVector`]s element by element, saturating at the numeric bounds.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![2; 200u8, 100].saturating_add(&vector![2; 100, 100]).unwrap();
+    /// assert_eq!(vec.as_slice(), &[255, 200]);
+    /// ```
+    pub fn saturating_add(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_map(other, SaturatingArith::saturating_add)
+    }
+
+    /// Subtracts two [`OwnedVector`]s element by element, saturating at the numeric bounds.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub fn saturating_sub(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_map(other, SaturatingArith::saturating_sub)
+    }
+
+    /// Multiplies two [`OwnedVector`]s element by element, saturating at the numeric bounds.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub fn saturating_mul(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.zip_map(other, SaturatingArith::saturating_mul)
+    }
+}
+
+impl<V: Signed> OwnedVector<V> {
+    /// Returns a new [`OwnedVector`] containing the absolute value of each element.
+    ///
+    /// # Panics
+    ///
+    /// * For integers the absolute value of the minimum value overflows.
+    ///   This panics in debug mode, just like the primitive `abs`.
+    #[must_use]
+    pub fn abs(&self) -> OwnedVector<V> {
+        self.map(Signed::abs)
+    }
+}
+
+impl<V: ByteConvert> OwnedVector<V> {
+    /// The number of header bytes storing `start` and `end` in the byte representation.
+    const HEADER_SIZE: usize = 2 * size_of::<u64>();
+
+    /// The tag identifying the encoding of [`OwnedVector::encode`].
+    const MAGIC: [u8; 4] = *b"OVEC";
+
+    /// Serializes the [`OwnedVector`] into a little-endian byte buffer.
+    ///
+    /// The buffer contains `start` and `end` as [`u64`] followed by the bytes of each element.
+    /// The fallbacks are not serialized.
+    #[must_use]
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(Self::HEADER_SIZE + self.len() * V::SIZE);
+        bytes.extend_from_slice(&(self.start as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.end as u64).to_le_bytes());
+
+        for &value in &self.vector {
+            value.to_le_bytes(&mut bytes);
+        }
+
+        bytes
+    }
+
+    /// Deserializes an [`OwnedVector`] from a little-endian byte buffer created by [`OwnedVector::to_le_bytes`].
+    ///
+    /// The stored `start` and `end` are used to validate the number of elements.
+    /// The returned vector begins at `start`, which allows loading the values at a different offset.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - The buffer is truncated.
+    ///   The header is incomplete or the element bytes are not a multiple of the element size.
+    /// * [`VectorError::Order`] - The stored `start` > the stored `end`.
+    /// * [`VectorError::Length`] - The number of elements does not match the stored `start` and `end`.
+    /// * [`VectorError::Overflow`] - The new `end` does not fit into a [`usize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, Vector, VectorError};
+    /// let bytes: Vec<u8> = vector![3; 1u16, 2, 3].to_le_bytes();
+    ///
+    /// let vec: OwnedVector<u16> = OwnedVector::from_le_bytes(&bytes, 10).unwrap();
+    /// assert_eq!((vec.start(), vec.end()), (10, 12));
+    /// assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    ///
+    /// let error = OwnedVector::<u16>::from_le_bytes(&bytes[..bytes.len() - 1], 10).unwrap_err();
+    /// assert_eq!(error, VectorError::Argument { name: "bytes" });
+    /// ```
+    pub fn from_le_bytes(bytes: &[u8], start: usize) -> Result<OwnedVector<V>, VectorError> {
+        const ERROR: VectorError = VectorError::Argument { name: "bytes" };
+
+        if bytes.len() < Self::HEADER_SIZE {
+            return Err(ERROR);
+        }
+        let (header, body): (&[u8], &[u8]) = bytes.split_at(Self::HEADER_SIZE);
+        let (stored_start, stored_end): (&[u8], &[u8]) = header.split_at(size_of::<u64>());
+        let stored_start: usize = Self::read_header(stored_start).ok_or(ERROR)?;
+        let stored_end: usize = Self::read_header(stored_end).ok_or(ERROR)?;
+
+        if body.len() % V::SIZE != 0 {
+            return Err(ERROR);
+        }
+        let vector: Vec<V> = body
+            .chunks_exact(V::SIZE)
+            .map(V::from_le_bytes)
+            .collect::<Option<Vec<V>>>()
+            .ok_or(ERROR)?;
+
+        VectorError::check_order(stored_start, stored_end)?;
+        VectorError::check_len(vector.len(), stored_start, stored_end)?;
+        let end: usize = VectorError::end_from_len(start, vector.len())?;
+
+        Ok(OwnedVector {
+            vector,
+            start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Encodes the [`OwnedVector`] into a self-contained binary format.
+    ///
+    /// The format consists of a magic tag followed by the output of [`OwnedVector::to_le_bytes`].
+    /// In contrast to [`OwnedVector::from_le_bytes`] decoding restores the stored `start`.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Self::MAGIC.to_vec();
+        bytes.extend_from_slice(&self.to_le_bytes());
+
+        bytes
+    }
+
+    /// Decodes an [`OwnedVector`] from bytes created by [`OwnedVector::encode`].
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - The magic tag is missing or the buffer is truncated.
+    /// * [`VectorError::Order`] - The stored `start` > the stored `end`.
+    /// * [`VectorError::Length`] - The number of elements does not match `end` - `start` + 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, VectorError};
+    /// let vec = vector![7; -1i32, 0, 1];
+    /// let bytes: Vec<u8> = vec.encode();
+    /// assert_eq!(OwnedVector::decode(&bytes), Ok(vec));
+    ///
+    /// let error = OwnedVector::<i32>::decode(&bytes[..bytes.len() - 2]).unwrap_err();
+    /// assert_eq!(error, VectorError::Argument { name: "bytes" });
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<OwnedVector<V>, VectorError> {
+        const ERROR: VectorError = VectorError::Argument { name: "bytes" };
+
+        let body: &[u8] = bytes.strip_prefix(&Self::MAGIC).ok_or(ERROR)?;
+        let start: usize = body
+            .get(..size_of::<u64>())
+            .and_then(Self::read_header)
+            .ok_or(ERROR)?;
+
+        Self::from_le_bytes(body, start)
+    }
+
+    /// Reads a single [`u64`] header value and converts it into a [`usize`].
+    ///
+    /// Returns [`None`] if the value does not fit into a [`usize`].
+    fn read_header(bytes: &[u8]) -> Option<usize> {
+        let value: u64 = u64::from_le_bytes(bytes.try_into().ok()?);
+        usize::try_from(value).ok()
+    }
+}
+
+impl OwnedVector<f64> {
+    /// Returns the weighted centroid (center of mass) along the index axis.
+    ///
+    /// This is `sum(index * value) / sum(value)` using the absolute indices.
+    /// If the total weight is zero [`None`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::vector;
+    /// let vec = vector![10; 1.0, 2.0, 4.0, 2.0, 1.0];
+    /// assert_eq!(vec.centroid(), Some(12.0));
+    /// ```
+    #[must_use]
+    pub fn centroid(&self) -> Option<f64> {
+        let (moment, weight): (f64, f64) = self.vector.iter().enumerate().fold(
+            (0.0, 0.0),
+            |(moment, weight), (offset, &value)| {
+                let index: f64 = (self.start + offset) as f64;
+                (moment + index * value, weight + value)
+            },
+        );
+
+        if weight == 0.0 {
+            None
+        } else {
+            Some(moment / weight)
+        }
+    }
+
+    /// Returns a new [`OwnedVector`] containing the sign preserving square root `sign(x) * sqrt(|x|)` of each element.
+    ///
+    /// In contrast to a plain square root negative values do not produce `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![3; 4.0, -9.0, 0.0].signed_sqrt();
+    /// assert_eq!(vec.as_slice(), &[2.0, -3.0, 0.0]);
+    /// ```
+    #[must_use]
+    pub fn signed_sqrt(&self) -> OwnedVector<f64> {
+        self.map(|value| value.signum() * value.abs().sqrt())
+    }
+
+    /// Returns the cross-correlation between `self` and `other` for all lags from `-max_lag` to `max_lag`.
+    ///
+    /// The correlation at `lag` is `sum(self[index] * other[index + lag])` over all absolute indices where both elements exist.
+    /// Lags without any overlapping elements have a correlation of zero.
+    /// Therefore, `max_lag` is clamped to the largest lag for which the ranges of both vectors can still overlap.
+    ///
+    /// As indices can not be negative, the result starts at zero and the correlation for `lag` is located at `lag + max_lag`.
+    /// Therefore, lag zero is found at index `max_lag`, which is `end / 2` of the result.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - The clamped `max_lag` does not fit into an [`isize`].
+    pub fn cross_correlate_lags(
+        &self,
+        other: &OwnedVector<f64>,
+        max_lag: usize,
+    ) -> Result<OwnedVector<f64>, VectorError> {
+        let overlap_lag: usize = self
+            .start
+            .abs_diff(other.end)
+            .max(self.end.abs_diff(other.start));
+        let max_lag: usize = max_lag.min(overlap_lag);
+        if isize::try_from(max_lag).is_err() {
+            return Err(VectorError::Argument { name: "max_lag" });
+        }
+        // Safe, because `max_lag` <= `isize::MAX`
+        let end: usize = 2 * max_lag;
+
+        let vector: Vec<f64> = (0..=end)
+            .map(|position| {
+                // Two's complement yields the correct signed difference, because it fits into an `isize`
+                let lag: isize = position.wrapping_sub(max_lag) as isize;
+
+                self.vector
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(offset, &value)| {
+                        let index: usize = (self.start + offset).checked_add_signed(lag)?;
+                        other.get(index).ok().map(|other_value| value * other_value)
+                    })
+                    .sum()
+            })
+            .collect();
+
+        Ok(OwnedVector {
+            vector,
+            start: 0,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Returns the lag from `-max_lag` to `max_lag` maximizing the [`OwnedVector::cross_correlate_lags`].
+    ///
+    /// A positive lag means that `other` is delayed compared to `self`.
+    /// Shifting `other` back by the lag aligns it best with `self`.
+    /// Ties resolve to the smallest lag.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - The clamped `max_lag` does not fit into an [`isize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, Vector};
+    /// let signal = vector![0; 0.0, 1.0, 3.0, 1.0, 0.0, 0.0, 0.0];
+    /// let delayed = vector![0; 0.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0];
+    /// assert_eq!(signal.best_lag(&delayed, 3), Ok(2));
+    /// assert_eq!(delayed.best_lag(&signal, 3), Ok(-2));
+    ///
+    /// // Lags without overlap are not computed
+    /// assert_eq!(signal.cross_correlate_lags(&delayed, usize::MAX).unwrap().len(), 13);
+    ///
+    /// // Lags beyond `isize::MAX` are rejected
+    /// let far = OwnedVector::from_vec(vec![1.0], usize::MAX, usize::MAX).unwrap();
+    /// assert!(signal.cross_correlate_lags(&far, usize::MAX).is_err());
+    /// ```
+    pub fn best_lag(&self, other: &OwnedVector<f64>, max_lag: usize) -> Result<isize, VectorError> {
+        let correlation: OwnedVector<f64> = self.cross_correlate_lags(other, max_lag)?;
+        let max_lag: usize = correlation.end / 2;
+
+        let (position, _): (usize, f64) = correlation.vector.iter().copied().enumerate().fold(
+            (0, f64::NEG_INFINITY),
+            |(best_position, best), (position, value)| {
+                if value > best {
+                    (position, value)
+                } else {
+                    (best_position, best)
+                }
+            },
+        );
+
+        // Two's complement yields the correct signed difference, because it fits into an `isize`
+        Ok(position.wrapping_sub(max_lag) as isize)
+    }
+
+    /// Detects change points using a cumulative sum (CUSUM) scan.
+    ///
+    /// The deviations of the elements from the mean are summed up.
+    /// Whenever the absolute value of this cumulative sum exceeds `threshold`, the absolute index is recorded.
+    /// Afterwards the cumulative sum is reset to zero, so the scan restarts directly after each change point.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - `threshold` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, VectorError};
+    /// let vec = vector![10; 0.0, 0.0, 0.0, 0.0, 10.0, 10.0, 10.0, 10.0];
+    /// // Without the reset after index 12 the sum would exceed the threshold again at index 13
+    /// assert_eq!(vec.cusum_changepoints(12.0), Ok(vec![12, 17]));
+    ///
+    /// let error = vec.cusum_changepoints(0.0).unwrap_err();
+    /// assert_eq!(error, VectorError::Argument { name: "threshold" });
+    /// ```
+    pub fn cusum_changepoints(&self, threshold: f64) -> Result<Vec<usize>, VectorError> {
+        if threshold <= 0.0 || threshold.is_nan() {
+            return Err(VectorError::Argument { name: "threshold" });
+        }
+
+        let mean: f64 = self.mean();
+
+        let mut cumulative: f64 = 0.0;
+        let mut changepoints: Vec<usize> = Vec::new();
+
+        for (offset, value) in self.vector.iter().enumerate() {
+            cumulative += value - mean;
+
+            if cumulative.abs() > threshold {
+                changepoints.push(self.start + offset);
+                cumulative = 0.0;
+            }
+        }
+
+        Ok(changepoints)
+    }
+
+    /// Returns the expected value of a discrete random variable with the outcomes `self` and the given `probabilities`.
+    ///
+    /// This is `sum(self[index] * probabilities[index])`.
+    /// The `probabilities` need to sum up to one within an absolute tolerance of `1e-9`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `probabilities` do not match.
+    /// * [`VectorError::Argument`] - The `probabilities` do not sum up to one.
+    pub fn expectation(&self, probabilities: &OwnedVector<f64>) -> Result<f64, VectorError> {
+        const TOLERANCE: f64 = 1e-9;
+
+        self.compatible(probabilities)?;
+
+        let total: f64 = probabilities.iter().sum();
+        if (total - 1.0).abs() > TOLERANCE || total.is_nan() {
+            return Err(VectorError::Argument {
+                name: "probabilities",
+            });
+        }
+
+        Ok(self
+            .vector
+            .iter()
+            .zip(probabilities)
+            .map(|(value, probability)| value * probability)
+            .sum())
+    }
+
+    /// Returns the cosine similarity `dot(self, other) / (|self| * |other|)` between two vectors.
+    ///
+    /// If either vector has a magnitude of zero, the result is `NaN`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub fn cosine_similarity(&self, other: &impl Vector<f64>) -> Result<f64, VectorError> {
+        self.compatible(other)?;
+
+        let (dot, squares_self, squares_other): (f64, f64, f64) =
+            self.vector.iter().zip(other.iter()).fold(
+                (0.0, 0.0, 0.0),
+                |(dot, squares_self, squares_other), (a, b)| {
+                    (dot + a * b, squares_self + a * a, squares_other + b * b)
+                },
+            );
+
+        let magnitude: f64 = squares_self.sqrt() * squares_other.sqrt();
+        if magnitude == 0.0 {
+            Ok(f64::NAN)
+        } else {
+            Ok(dot / magnitude)
+        }
+    }
+
+    /// Returns the Euclidean distance `sqrt(sum((self - other)^2))` between two vectors.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub fn distance(&self, other: &impl Vector<f64>) -> Result<f64, VectorError> {
+        self.compatible(other)?;
+
+        Ok(self
+            .vector
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt())
+    }
+
+    /// Creates a new [`OwnedVector`] with `n` evenly spaced values from `start_value` to `end_value` inclusive.
+    ///
+    /// The values are located at the indices beginning at `index_start`.
+    /// For `n` = 1 the only value is `start_value`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `n` == 0.
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    pub fn linspace(
+        start_value: f64,
+        end_value: f64,
+        n: usize,
+        index_start: usize,
+    ) -> Result<OwnedVector<f64>, VectorError> {
+        let end: usize = VectorError::end_from_len(index_start, n)?;
+
+        let vector: Vec<f64> = if n == 1 {
+            vec![start_value]
+        } else {
+            let step: f64 = (end_value - start_value) / (n - 1) as f64;
+            (0..n)
+                .map(|position| {
+                    // Avoid rounding errors at the last value
+                    if position == n - 1 {
+                        end_value
+                    } else {
+                        start_value + position as f64 * step
+                    }
+                })
+                .collect()
+        };
+
+        Ok(OwnedVector {
+            vector,
+            start: index_start,
+            end,
+            fallback: None,
+        })
+    }
+
+    /// Resamples the vector onto `new_len` evenly spaced points using linear interpolation.
+    ///
+    /// The first and last point coincide with the first and last element.
+    /// The values are located at the indices beginning at `index_start`.
+    /// For `new_len` = 1 the only value is the first element.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `new_len` == 0.
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    pub fn resample(
+        &self,
+        new_len: usize,
+        index_start: usize,
+    ) -> Result<OwnedVector<f64>, VectorError> {
+        let last: usize = self.vector.len() - 1;
+        let positions: OwnedVector<f64> =
+            OwnedVector::linspace(0.0, last as f64, new_len, index_start)?;
+
+        Ok(positions.map(|position| {
+            let lower: usize = (position.floor() as usize).min(last);
+            let upper: usize = (lower + 1).min(last);
+            let fraction: f64 = position - lower as f64;
+
+            // Avoid `NaN` for infinite values at exact positions
+            if lower == upper || fraction == 0.0 {
+                self.vector[lower]
+            } else {
+                self.vector[lower] + (self.vector[upper] - self.vector[lower]) * fraction
+            }
+        }))
+    }
+
+    /// Linearly interpolates between `self` and `other` element by element with `a * (1 - t) + b * t`.
+    ///
+    /// A `t` outside of `[0, 1]` extrapolates instead of clamping.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub fn lerp(&self, other: &impl Vector<f64>, t: f64) -> Result<OwnedVector<f64>, VectorError> {
+        self.zip_map(other, |a, b| a * (1.0 - t) + b * t)
+    }
+
+    /// Returns the count, mean and population variance of all elements computed in a single pass.
+    ///
+    /// This uses Welford's algorithm, which avoids the catastrophic cancellation of the naive sum of squares.
+    /// The variance is divided by the count, so it corresponds to [`OwnedVector::variance`] with `ddof` = 0.
+    #[must_use]
+    pub fn running_stats(&self) -> (f64, f64, f64) {
+        let mut count: f64 = 0.0;
+        let mut mean: f64 = 0.0;
+        let mut squares: f64 = 0.0;
+
+        for &value in &self.vector {
+            count += 1.0;
+            let delta: f64 = value - mean;
+            mean += delta / count;
+            squares += delta * (value - mean);
+        }
+
+        (count, mean, squares / count)
+    }
+
+    /// Returns a new [`OwnedVector`] rescaled so that the minimum maps to 0.0 and the maximum to 1.0.
+    ///
+    /// If all elements are equal or the range is infinite, every element maps to 0.0 to avoid a division by zero or infinity.
+    /// `NaN` elements are ignored when searching the minimum and maximum and stay `NaN`.
+    #[must_use]
+    pub fn min_max_normalize(&self) -> OwnedVector<f64> {
+        let min: f64 = self.vector.iter().copied().fold(f64::INFINITY, f64::min);
+        let max: f64 = self
+            .vector
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let range: f64 = max - min;
+
+        if range > 0.0 && range.is_finite() {
+            self.map(|value| (value - min) / range)
+        } else {
+            self.map(|value| if value.is_nan() { value } else { 0.0 })
+        }
+    }
+
+    /// Returns the median of all elements.
+    ///
+    /// For an even number of elements this is the average of the two middle elements.
+    #[must_use]
+    pub fn median(&self) -> f64 {
+        // Safe, because 0.5 is inside of [0, 1]
+        self.quantile(0.5).unwrap()
+    }
+
+    /// Returns the `q`-quantile of all elements.
+    ///
+    /// The elements are cloned and sorted, so the vector is not mutated.
+    /// Between two order statistics the result is linearly interpolated.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - `q` is outside of `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, VectorError};
+    /// let vec = vector![0; 4.0, 1.0, 3.0, 2.0];
+    /// assert_eq!(vec.quantile(0.25), Ok(1.75));
+    /// assert_eq!(vec.median(), 2.5);
+    ///
+    /// let error = vec.quantile(1.5).unwrap_err();
+    /// assert_eq!(error, VectorError::Argument { name: "q" });
+    /// ```
+    pub fn quantile(&self, q: f64) -> Result<f64, VectorError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(VectorError::Argument { name: "q" });
+        }
+
+        let mut sorted: Vec<f64> = self.vector.clone();
+        sorted.sort_unstable_by(f64::total_cmp);
 
-    /// The end to allow to assertion of the correct length.
+        let position: f64 = q * (sorted.len() - 1) as f64;
+        let lower: usize = position.floor() as usize;
+        let upper: usize = position.ceil() as usize;
+
+        if lower == upper {
+            Ok(sorted[lower])
+        } else {
+            Ok(sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64))
+        }
+    }
+
+    /// Returns the moving average, where each element is the mean of `window` consecutive elements.
     ///
-    /// Note that the end is included.
-    end: usize,
+    /// The element at `index` is the mean of the elements from `index - window + 1` to `index`.
+    /// As the first `window - 1` elements do not have enough predecessors, the result ranges from `start + window - 1` to `end`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Argument`] - `window` == 0 or `window` > `len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![10; 1.0, 2.0, 3.0, 4.0].moving_average(2).unwrap();
+    /// assert_eq!(vec.start(), 11);
+    /// assert_eq!(vec.as_slice(), &[1.5, 2.5, 3.5]);
+    /// ```
+    pub fn moving_average(&self, window: usize) -> Result<OwnedVector<f64>, VectorError> {
+        if window == 0 || window > self.vector.len() {
+            return Err(VectorError::Argument { name: "window" });
+        }
+
+        let vector: Vec<f64> = self
+            .vector
+            .windows(window)
+            .map(|values| values.iter().sum::<f64>() / window as f64)
+            .collect();
+
+        Ok(OwnedVector {
+            vector,
+            start: self.start + window - 1,
+            end: self.end,
+            fallback: None,
+        })
+    }
 }
 
-impl<V: Vectorable> OwnedVector<V> {
-    /// Creates a new [`OwnedVector`] instance based on a given [`Vec`].
+impl<V: Float> OwnedVector<V> {
+    /// Returns a new [`OwnedVector`] with each element raised to the floating point power `exp`.
+    ///
+    /// Negative elements with a fractional `exp` produce `NaN`, just like [`f64::powf`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![3; 4.0, 9.0];
+    /// assert_eq!(vec.powf(0.5).as_slice(), &[2.0, 3.0]);
+    /// assert_eq!(vec.powi(2).as_slice(), &[16.0, 81.0]);
+    /// ```
+    #[must_use]
+    pub fn powf(&self, exp: V) -> OwnedVector<V> {
+        self.map(|value| value.powf(exp))
+    }
+
+    /// Returns a new [`OwnedVector`] with each element raised to the integer power `exp`.
+    ///
+    /// This is generally faster than [`OwnedVector::powf`].
+    #[must_use]
+    pub fn powi(&self, exp: i32) -> OwnedVector<V> {
+        self.map(|value| value.powi(exp))
+    }
+
+    /// Returns a new [`OwnedVector`] containing the square root of each element.
+    ///
+    /// Negative elements produce `NaN`, just like [`f64::sqrt`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, Vector};
+    /// let vec = vector![3; 4.0, 9.0].sqrt();
+    /// assert_eq!(vec.as_slice(), &[2.0, 3.0]);
+    /// ```
+    #[must_use]
+    pub fn sqrt(&self) -> OwnedVector<V> {
+        self.map(Float::sqrt)
+    }
+
+    /// Returns a new [`OwnedVector`] containing `e` raised to the power of each element.
+    #[must_use]
+    pub fn exp(&self) -> OwnedVector<V> {
+        self.map(Float::exp)
+    }
+
+    /// Returns a new [`OwnedVector`] containing the natural logarithm of each element.
+    ///
+    /// Zero produces `-inf` and negative elements produce `NaN`, just like [`f64::ln`].
+    #[must_use]
+    pub fn ln(&self) -> OwnedVector<V> {
+        self.map(Float::ln)
+    }
+
+    /// Returns a new [`OwnedVector`] containing the sine of each element in radians.
+    #[must_use]
+    pub fn sin(&self) -> OwnedVector<V> {
+        self.map(Float::sin)
+    }
+
+    /// Returns a new [`OwnedVector`] containing the cosine of each element in radians.
+    #[must_use]
+    pub fn cos(&self) -> OwnedVector<V> {
+        self.map(Float::cos)
+    }
+
+    /// Returns the L1 norm `sum(|x|)`.
+    #[must_use]
+    pub fn norm_l1(&self) -> V {
+        self.vector.iter().map(|&value| value.abs()).sum()
+    }
+
+    /// Returns the L-infinity norm `max(|x|)`.
+    #[must_use]
+    pub fn norm_linf(&self) -> V {
+        self.vector
+            .iter()
+            .map(|&value| value.abs())
+            .fold(V::ZERO, Float::max)
+    }
+
+    /// Returns the Lp norm `sum(|x|^p)^(1 / p)`.
+    ///
+    /// For `p` = 1 this equals [`OwnedVector::norm_l1`] and for large `p` it approaches [`OwnedVector::norm_linf`].
+    /// For `p` <= 0 this is not a norm and follows the floating point semantics, producing values like `inf` or `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::vector;
+    /// let vec = vector![0; 3.0, -4.0];
+    /// assert_eq!(vec.norm_l1(), 7.0);
+    /// assert_eq!(vec.norm_linf(), 4.0);
+    /// assert_eq!(vec.norm_lp(2.0), 5.0);
+    /// ```
+    #[must_use]
+    pub fn norm_lp(&self, p: V) -> V {
+        self.vector
+            .iter()
+            .map(|&value| value.abs().powf(p))
+            .sum::<V>()
+            .powf(p.recip())
+    }
+
+    /// Returns the arithmetic mean of all elements.
+    #[must_use]
+    pub fn mean(&self) -> V {
+        self.vector.iter().copied().sum::<V>() / V::from_usize(self.vector.len())
+    }
+
+    /// Returns the variance of all elements with `ddof` delta degrees of freedom.
+    ///
+    /// The sum of squared deviations is divided by `len - ddof`.
+    /// Therefore, `ddof` = 0 yields the population and `ddof` = 1 the sample variance.
+    /// If `ddof` >= `len` the result is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::vector;
+    /// let vec = vector![0; 1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(vec.mean(), 2.5);
+    /// assert_eq!(vec.variance(0), 1.25);
+    ///
+    /// let undefined: f64 = vec.variance(4);
+    /// assert!(undefined.is_nan());
+    /// ```
+    #[must_use]
+    pub fn variance(&self, ddof: usize) -> V {
+        if ddof >= self.vector.len() {
+            return V::NAN;
+        }
+
+        let mean: V = self.mean();
+        let squares: V = self
+            .vector
+            .iter()
+            .map(|&value| (value - mean) * (value - mean))
+            .sum();
+
+        squares / V::from_usize(self.vector.len() - ddof)
+    }
+
+    /// Returns the standard deviation of all elements with `ddof` delta degrees of freedom.
+    ///
+    /// This is the square root of [`OwnedVector::variance`].
+    #[must_use]
+    pub fn std_dev(&self, ddof: usize) -> V {
+        self.variance(ddof).sqrt()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<V: Vectorable + Send + Sync> OwnedVector<V> {
+    /// Applies `function` to every element in parallel and collects the results into a new [`OwnedVector`].
+    ///
+    /// The `start` and `end` are preserved.
+    pub fn par_map<U, F>(&self, function: F) -> OwnedVector<U>
+    where
+        U: Vectorable + Send,
+        F: Fn(V) -> U + Send + Sync,
+    {
+        OwnedVector {
+            vector: self
+                .vector
+                .par_iter()
+                .map(|&value| function(value))
+                .collect(),
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        }
+    }
+
+    /// Sums all elements in parallel.
+    ///
+    /// Note that for floating point types the result may differ slightly from a serial sum.
+    #[must_use]
+    pub fn par_sum(&self) -> V
+    where
+        V: std::iter::Sum<V>,
+    {
+        self.vector.par_iter().copied().sum()
+    }
+
+    /// Reduces all elements in parallel using `function` starting from `identity`.
+    ///
+    /// `function` needs to be associative and `identity` its neutral element, as the elements are combined in an unspecified order.
+    /// Note that for floating point types the result may differ slightly from a serial reduction.
+    #[must_use]
+    pub fn par_reduce<F>(&self, identity: V, function: F) -> V
+    where
+        F: Fn(V, V) -> V + Send + Sync,
+    {
+        self.vector
+            .par_iter()
+            .copied()
+            .reduce(|| identity, function)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<V: Vectorable + Scalar> OwnedVector<V> {
+    /// Converts the vector into a [`DVector`].
+    ///
+    /// The `start` and `end` are dropped, so the element at `start` is located at row 0.
+    #[must_use]
+    pub fn to_dvector(&self) -> DVector<V> {
+        DVector::from_column_slice(&self.vector)
+    }
+
+    /// Creates a new [`OwnedVector`] from a [`DVector`] beginning at `start`.
+    ///
+    /// The `end` is calculated from the length of `dvector`.
     ///
     /// # Errors
     ///
-    /// * [`VectorError::Order`] - The order of the arguments is wrong.
-    /// `start` > `end`.
-    /// * [`VectorError::Length`] - The expected length does not match the provided one.
-    /// `vec.len() != end - start + 1`.
-    #[inline]
-    pub fn from_vec(vec: Vec<V>, start: usize, end: usize) -> Result<Self, VectorError> {
-        // Not possible as const fn (Vec deconstruction)
-        VectorError::check_order(start, end)?;
-        VectorError::check_len(vec.len(), start, end)?;
+    /// * [`VectorError::Empty`] - `dvector` does not contain any elements.
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, Vector};
+    /// let dvector = vector![4; 1.0, 2.0, 3.0].to_dvector();
+    /// assert_eq!(dvector.as_slice(), &[1.0, 2.0, 3.0]);
+    ///
+    /// let vec: OwnedVector<f64> = OwnedVector::from_dvector(dvector, 8).unwrap();
+    /// assert_eq!((vec.start(), vec.end()), (8, 10));
+    /// assert_eq!(vec.as_slice(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn from_dvector(dvector: DVector<V>, start: usize) -> Result<Self, VectorError> {
+        let end: usize = VectorError::end_from_len(start, dvector.len())?;
 
         Ok(Self {
-            vector: vec,
+            vector: dvector.data.into(),
             start,
             end,
+            fallback: None,
         })
     }
+}
 
-    /// Creates a new [`OwnedVector`] with a given `value` at all positions.
-    ///
-    /// # Errors
+#[cfg(feature = "ndarray")]
+impl<V: Vectorable> OwnedVector<V> {
+    /// Converts the vector into an [`Array1`].
     ///
-    /// * [`VectorError::Order`] - `start` > `end`.
-    pub fn from_num(value: V, start: usize, end: usize) -> Result<Self, VectorError> {
-        VectorError::check_order(start, end)?;
-
-        // `end` - `start` si safe, because `check_order` passed
-        let vector: Vec<V> = vec![value; end - start + 1];
-
-        Ok(Self { vector, start, end })
+    /// The `start` and `end` are dropped, so the element at `start` is located at position 0.
+    #[must_use]
+    pub fn to_ndarray(&self) -> Array1<V> {
+        Array1::from_vec(self.vector.clone())
     }
 
-    /// Returns an [`IterMut`] of the underlying [`Vec`].
+    /// Creates a new [`OwnedVector`] from an [`Array1`] beginning at `start`.
     ///
-    /// This is simply a getter of the `iter_mut` and will not consider the offest indexing.
-    #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
-        self.vector.iter_mut()
+    /// The `end` is calculated from the length of `array`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `array` does not contain any elements.
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{vector, OwnedVector, Vector};
+    /// let array = vector![4; 1.0, 2.0, 3.0].to_ndarray();
+    /// assert_eq!(array.to_vec(), vec![1.0, 2.0, 3.0]);
+    ///
+    /// let vec: OwnedVector<f64> = OwnedVector::from_ndarray(array, 8).unwrap();
+    /// assert_eq!((vec.start(), vec.end()), (8, 10));
+    /// assert_eq!(vec.as_slice(), &[1.0, 2.0, 3.0]);
+    /// ```
+    pub fn from_ndarray(array: Array1<V>, start: usize) -> Result<Self, VectorError> {
+        let end: usize = VectorError::end_from_len(start, array.len())?;
+
+        Ok(Self {
+            vector: array.to_vec(),
+            start,
+            end,
+            fallback: None,
+        })
     }
 }
 
-impl<V: Default + Vectorable> OwnedVector<V> {
-    /// Creates a new [`OwnedVector`] instance based on a given `start` and `end`.
-    /// This will be filled with the [`Default`] value of the generic.
+#[cfg(feature = "simd")]
+impl<V: SimdVectorable> OwnedVector<V> {
+    /// Adds two [`OwnedVector`]s element by element using SIMD lanes.
+    ///
+    /// The result is identical to adding the elements one by one.
     ///
     /// # Errors
     ///
-    /// * [`VectorError::Order`] - The order of the arguments is wrong.
-    /// `start` > `end`.
-    pub fn new(start: usize, end: usize) -> Result<Self, VectorError> {
-        VectorError::check_order(start, end)?;
-
-        let vector: Vec<V> = vec![V::default(); end - start + 1];
+    /// * [`VectorError::Compatibility`] - The `start` or `end` of `other` do not match.
+    pub fn add_simd(&self, other: &OwnedVector<V>) -> Result<OwnedVector<V>, VectorError> {
+        self.compatible(other)?;
 
-        Ok(Self { vector, start, end })
+        Ok(Self {
+            vector: V::add_slices(&self.vector, &other.vector),
+            start: self.start,
+            end: self.end,
+            fallback: None,
+        })
     }
 }
 
@@ -90,26 +2449,48 @@ impl<V: Vectorable> Index<usize> for OwnedVector<V> {
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        // Underflow will wrap around and panic
-        &self.vector[index.wrapping_sub(self.start)]
+        match &self.fallback {
+            Some((fallback_start, _)) if index < self.start => fallback_start,
+            Some((_, fallback_end)) if index > self.end => fallback_end,
+            _ => &self.vector[index_offset(index, self.start, self.end)],
+        }
     }
 }
 
 impl<V: Vectorable> IndexMut<usize> for OwnedVector<V> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        // Underflow will wrap around and panic
-        &mut self.vector[index.wrapping_sub(self.start)]
+        &mut self.vector[index_offset(index, self.start, self.end)]
     }
 }
 
 impl<V: Vectorable> Index<RangeInclusive<usize>> for OwnedVector<V> {
     type Output = [V];
 
+    /// Returns the elements from the absolute `start` to `end` of the `range`.
+    ///
+    /// # Panics
+    ///
+    /// * A bound of the `range` is below `start` or above `end`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::panic::catch_unwind;
+    /// # use vector::vector;
+    /// let vec = vector![5; 1, 2, 3];
+    /// assert_eq!(&vec[6..=7], &[2, 3]);
+    ///
+    /// let error = catch_unwind(|| vec[3..=6].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 3 is below start 5");
+    ///
+    /// let error = catch_unwind(|| vec[6..=8].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 8 is above end 7");
+    /// ```
     #[inline]
     fn index(&self, range: RangeInclusive<usize>) -> &Self::Output {
-        let start: usize = range.start() - self.start;
-        let end: usize = range.end() - self.start;
+        let start: usize = index_offset(*range.start(), self.start, self.end);
+        let end: usize = index_offset(*range.end(), self.start, self.end);
 
         &self.vector[start..=end]
     }
@@ -118,10 +2499,30 @@ impl<V: Vectorable> Index<RangeInclusive<usize>> for OwnedVector<V> {
 impl<V: Vectorable> Index<Range<usize>> for OwnedVector<V> {
     type Output = [V];
 
+    /// Returns the elements from the absolute `start` up to but excluding the `end` of the `range`.
+    ///
+    /// # Panics
+    ///
+    /// * A bound of the `range` is below `start` or above `end` + 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::panic::catch_unwind;
+    /// # use vector::vector;
+    /// let vec = vector![5; 1, 2, 3];
+    /// assert_eq!(&vec[6..8], &[2, 3]);
+    ///
+    /// let error = catch_unwind(|| vec[3..6].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 3 is below start 5");
+    ///
+    /// let error = catch_unwind(|| vec[6..9].len()).unwrap_err();
+    /// assert_eq!(error.downcast_ref::<String>().unwrap(), "index 9 is above end 7");
+    /// ```
     #[inline]
     fn index(&self, range: Range<usize>) -> &Self::Output {
-        let start: usize = range.start - self.start;
-        let end: usize = range.end - self.start;
+        let start: usize = exclusive_offset(range.start, self.start, self.end);
+        let end: usize = exclusive_offset(range.end, self.start, self.end);
 
         &self.vector[start..end]
     }
@@ -144,6 +2545,8 @@ impl<V: Vectorable> Vector<V> for OwnedVector<V> {
     }
 
     fn slice(&'_ self, start: usize, end: usize) -> Result<BorrowedVector<'_, V>, VectorError> {
+        VectorError::check_order(start, end)?;
+
         if start >= self.start && end <= self.end {
             let internal_start: usize = start - self.start;
             let internal_end: usize = end - self.start;
@@ -182,9 +2585,10 @@ impl<V: Vectorable> Vector<V> for OwnedVector<V> {
     fn get_range(&self, start: usize, end: usize) -> Result<&[V], VectorError> {
         VectorError::check_order(start, end)?;
 
+        // `end` - 1 > `self.end` is equivalent to `end` > `self.end` + 1, but protects from overflow
         if start < self.start {
             Err(VectorError::Indexing { index: self.start })
-        } else if end > self.end + 1 {
+        } else if end.saturating_sub(1) > self.end {
             Err(VectorError::Indexing { index: self.end })
         } else {
             let start_offest: usize = start - self.start;
@@ -210,6 +2614,84 @@ impl<V: Vectorable> Vector<V> for OwnedVector<V> {
     }
 }
 
+impl<V: Vectorable> Extend<V> for OwnedVector<V> {
+    /// Appends all elements of `iter` and increases the `end` accordingly.
+    ///
+    /// The `start` stays fixed.
+    ///
+    /// # Panics
+    ///
+    /// * The new `end` does not fit into a [`usize`].
+    ///   Use [`OwnedVector::push`] for a non-panicking version.
+    fn extend<I: IntoIterator<Item = V>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.vector.reserve(iter.size_hint().0);
+
+        for value in iter {
+            if let Err(error) = self.push(value) {
+                panic!("{}", error);
+            }
+        }
+    }
+}
+
+impl<V: Vectorable + Default> Default for OwnedVector<V> {
+    /// Creates a new [`OwnedVector`] with a single default element at index 0.
+    ///
+    /// As a vector can not be empty, this is the smallest possible vector.
+    fn default() -> Self {
+        Self {
+            vector: vec![V::default()],
+            start: 0,
+            end: 0,
+            fallback: None,
+        }
+    }
+}
+
+impl<V: Vectorable, const N: usize> From<[V; N]> for OwnedVector<V> {
+    /// Creates a new [`OwnedVector`] from an array with `start` = 0 and `end` = `N` - 1.
+    ///
+    /// Empty arrays are rejected at compile time.
+    fn from(array: [V; N]) -> Self {
+        const { assert!(N > 0, "an `OwnedVector` can not be empty") };
+
+        Self {
+            vector: Vec::from(array),
+            start: 0,
+            end: N - 1,
+            fallback: None,
+        }
+    }
+}
+
+impl<V: Vectorable> TryFrom<Vec<V>> for OwnedVector<V> {
+    type Error = VectorError;
+
+    /// Creates a new [`OwnedVector`] from a [`Vec`] with `start` = 0 and `end` = `len` - 1.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - The [`Vec`] does not contain any elements.
+    fn try_from(vec: Vec<V>) -> Result<Self, Self::Error> {
+        let end: usize = VectorError::end_from_len(0, vec.len())?;
+
+        Ok(Self {
+            vector: vec,
+            start: 0,
+            end,
+            fallback: None,
+        })
+    }
+}
+
+impl<V: Vectorable> From<OwnedVector<V>> for Vec<(usize, V)> {
+    /// Converts the [`OwnedVector`] into `(index, value)` pairs using the absolute indices.
+    fn from(vector: OwnedVector<V>) -> Self {
+        vector.into_indexed().collect()
+    }
+}
+
 impl<V: Vectorable> IntoIterator for OwnedVector<V> {
     type Item = V;
     type IntoIter = std::vec::IntoIter<V>;
@@ -236,3 +2718,170 @@ impl<'a, V: Vectorable> IntoIterator for &'a mut OwnedVector<V> {
         self.iter_mut()
     }
 }
+
+impl<V: Vectorable + Add<Output = V>> Add<V> for &OwnedVector<V> {
+    type Output = OwnedVector<V>;
+
+    /// Adds the scalar `rhs` to every element.
+    #[inline]
+    fn add(self, rhs: V) -> Self::Output {
+        self.map(|value| value + rhs)
+    }
+}
+
+impl<V: Vectorable + Sub<Output = V>> Sub<V> for &OwnedVector<V> {
+    type Output = OwnedVector<V>;
+
+    /// Subtracts the scalar `rhs` from every element.
+    #[inline]
+    fn sub(self, rhs: V) -> Self::Output {
+        self.map(|value| value - rhs)
+    }
+}
+
+impl<V: Vectorable + Neg<Output = V>> Neg for &OwnedVector<V> {
+    type Output = OwnedVector<V>;
+
+    /// Negates every element.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.map(|value| -value)
+    }
+}
+
+impl<V: Vectorable + AddAssign> AddAssign<&OwnedVector<V>> for OwnedVector<V> {
+    /// Adds `rhs` element by element in place.
+    ///
+    /// # Panics
+    ///
+    /// * The `start` or `end` of `rhs` do not match.
+    ///   Use [`OwnedVector::try_add_assign`] for a non-panicking version.
+    fn add_assign(&mut self, rhs: &OwnedVector<V>) {
+        if let Err(error) = self.try_add_assign(rhs) {
+            panic!("{}", error);
+        }
+    }
+}
+
+impl<V: Vectorable + SubAssign> SubAssign<&OwnedVector<V>> for OwnedVector<V> {
+    /// Subtracts `rhs` element by element in place.
+    ///
+    /// # Panics
+    ///
+    /// * The `start` or `end` of `rhs` do not match.
+    ///   Use [`OwnedVector::try_sub_assign`] for a non-panicking version.
+    fn sub_assign(&mut self, rhs: &OwnedVector<V>) {
+        if let Err(error) = self.try_sub_assign(rhs) {
+            panic!("{}", error);
+        }
+    }
+}
+
+impl<V: Vectorable + MulAssign> MulAssign<&OwnedVector<V>> for OwnedVector<V> {
+    /// Multiplies `rhs` element by element in place.
+    ///
+    /// # Panics
+    ///
+    /// * The `start` or `end` of `rhs` do not match.
+    ///   Use [`OwnedVector::try_mul_assign`] for a non-panicking version.
+    fn mul_assign(&mut self, rhs: &OwnedVector<V>) {
+        if let Err(error) = self.try_mul_assign(rhs) {
+            panic!("{}", error);
+        }
+    }
+}
+
+impl<V: Vectorable + DivAssign> DivAssign<&OwnedVector<V>> for OwnedVector<V> {
+    /// Divides `rhs` element by element in place.
+    ///
+    /// # Panics
+    ///
+    /// * The `start` or `end` of `rhs` do not match.
+    ///   Use [`OwnedVector::try_div_assign`] for a non-panicking version.
+    fn div_assign(&mut self, rhs: &OwnedVector<V>) {
+        if let Err(error) = self.try_div_assign(rhs) {
+            panic!("{}", error);
+        }
+    }
+}
+
+impl<V: Vectorable + AddAssign> Sum for OwnedVector<V> {
+    /// Sums all vectors element by element.
+    ///
+    /// # Panics
+    ///
+    /// * The iterator does not yield any vector.
+    /// * The `start` or `end` of the vectors do not match.
+    ///   Use [`OwnedVector::try_sum`] for a non-panicking version.
+    fn sum<I: Iterator<Item = OwnedVector<V>>>(iter: I) -> Self {
+        match OwnedVector::try_sum(iter) {
+            Ok(vector) => vector,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl<V: Vectorable + MulAssign> Product for OwnedVector<V> {
+    /// Multiplies all vectors element by element.
+    ///
+    /// # Panics
+    ///
+    /// * The iterator does not yield any vector.
+    /// * The `start` or `end` of the vectors do not match.
+    ///   Use [`OwnedVector::try_product`] for a non-panicking version.
+    fn product<I: Iterator<Item = OwnedVector<V>>>(iter: I) -> Self {
+        match OwnedVector::try_product(iter) {
+            Ok(vector) => vector,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl<V: Vectorable + Display> Display for OwnedVector<V> {
+    /// Formats the range followed by each absolute index and value pair.
+    ///
+    /// For example `[5..=7] {5: 10, 6: 20, 7: 30}`.
+    fn fmt(&self, format: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(format, "[{}..={}] {{", self.start, self.end)?;
+
+        for (offset, value) in self.vector.iter().enumerate() {
+            if offset > 0 {
+                write!(format, ", ")?;
+            }
+            write!(format, "{}: {}", self.start + offset, value)?;
+        }
+
+        write!(format, "}}")
+    }
+}
+
+impl<V: Vectorable + PartialEq> PartialEq for OwnedVector<V> {
+    /// Two vectors are equal, if their `start`, `end` and elements are equal.
+    ///
+    /// The fallbacks are ignored on purpose, as they only affect out-of-range indexing and not the stored elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::OwnedVector;
+    /// let plain = OwnedVector::from_vec(vec![1, 2], 3, 4).unwrap();
+    /// let bounded = OwnedVector::from_vec_with_fallback(vec![1, 2], 3, 4, 0, 0).unwrap();
+    /// assert_eq!(plain, bounded);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.vector == other.vector
+    }
+}
+
+impl<V: Vectorable + Eq> Eq for OwnedVector<V> {}
+
+impl<V: Vectorable + Hash> Hash for OwnedVector<V> {
+    /// Hashes the `start`, `end` and the elements in order.
+    ///
+    /// Just like [`PartialEq`] the fallbacks are ignored, so equal vectors always have equal hashes.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+        self.vector.hash(state);
+    }
+}
@@ -0,0 +1,48 @@
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Vectorable;
+
+/// Helper trait for the saturating arithmetic of [`OwnedVector`].
+///
+/// This trait is automatically implemented for all integer types.
+pub trait SaturatingArith: Vectorable {
+    /// Saturating addition, clamping at the numeric bounds.
+    #[must_use]
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Saturating subtraction, clamping at the numeric bounds.
+    #[must_use]
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Saturating multiplication, clamping at the numeric bounds.
+    #[must_use]
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+/// Implements [`SaturatingArith`] by forwarding to the inherent methods of the primitive.
+macro_rules! impl_saturating_arith {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingArith for $t {
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$t>::saturating_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_arith!(
+    i128, i64, i32, i16, i8, isize, u128, u64, u32, u16, u8, usize
+);
@@ -0,0 +1,30 @@
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Vectorable;
+
+/// Helper trait for the signed numerics in [`OwnedVector`].
+///
+/// This trait is automatically implemented for all signed integer and floating point types.
+pub trait Signed: Vectorable {
+    /// Returns the absolute value of `self`.
+    ///
+    /// For integers this follows the overflow behavior of the primitive `abs`.
+    #[must_use]
+    fn abs(self) -> Self;
+}
+
+/// Implements [`Signed`] by forwarding to the inherent `abs` of the primitive.
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                #[inline]
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(f64, f32, i128, i64, i32, i16, i8, isize);
@@ -0,0 +1,50 @@
+use wide::{f32x8, f64x4};
+
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Vectorable;
+
+/// Helper trait for the SIMD accelerated operations of [`OwnedVector`].
+///
+/// This trait is automatically implemented for [`f64`] and [`f32`].
+pub trait SimdVectorable: Vectorable {
+    /// Adds two slices of equal length element by element.
+    ///
+    /// Full lanes are processed in bulk, the remainder is added one by one.
+    #[must_use]
+    fn add_slices(lhs: &[Self], rhs: &[Self]) -> Vec<Self>;
+}
+
+/// Implements [`SimdVectorable`] for a primitive and its corresponding `wide` lane type.
+macro_rules! impl_simd_vectorable {
+    ($($t:ty => $lane:ty, $width:literal);*) => {
+        $(
+            impl SimdVectorable for $t {
+                fn add_slices(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+                    debug_assert_eq!(lhs.len(), rhs.len());
+
+                    let mut sum: Vec<Self> = Vec::with_capacity(lhs.len());
+
+                    let lhs_chunks = lhs.chunks_exact($width);
+                    let rhs_chunks = rhs.chunks_exact($width);
+                    let lhs_remainder: &[Self] = lhs_chunks.remainder();
+                    let rhs_remainder: &[Self] = rhs_chunks.remainder();
+
+                    for (lhs_chunk, rhs_chunk) in lhs_chunks.zip(rhs_chunks) {
+                        // Safe, because `chunks_exact` only yields full lanes
+                        let lhs_lane: $lane = <$lane>::from(<[Self; $width]>::try_from(lhs_chunk).unwrap());
+                        let rhs_lane: $lane = <$lane>::from(<[Self; $width]>::try_from(rhs_chunk).unwrap());
+
+                        sum.extend_from_slice(&(lhs_lane + rhs_lane).to_array());
+                    }
+
+                    sum.extend(lhs_remainder.iter().zip(rhs_remainder).map(|(lhs, rhs)| lhs + rhs));
+
+                    sum
+                }
+            }
+        )*
+    };
+}
+
+impl_simd_vectorable!(f64 => f64x4, 4; f32 => f32x8, 8);
@@ -0,0 +1,166 @@
+use std::slice::Iter;
+
+use crate::{OwnedVector, VectorError, Vectorable};
+
+#[allow(unused_imports)]
+use crate::Vector;
+
+/// A sparse vector storing only the present elements of a range together with their absolute index.
+///
+/// Absent indices between `start` and `end` resolve to the `fallback`.
+/// In contrast to [`OwnedVector`] the memory usage only depends on the number of present elements.
+///
+/// As there is no contiguous storage, the [`Vector`] trait is not implemented.
+///
+/// The generic value needs to implement the [`Vectorable`] trait.
+#[derive(Debug, Clone)]
+pub struct SparseVector<V: Vectorable> {
+    /// The present elements as `(index, value)` pairs sorted by their absolute index.
+    pairs: Vec<(usize, V)>,
+
+    /// The start of the declared range.
+    start: usize,
+
+    /// The end of the declared range.
+    ///
+    /// Note that the end is included.
+    end: usize,
+
+    /// The value returned for absent indices.
+    fallback: V,
+}
+
+// A `SparseVector` always covers at least one index, so an `is_empty` would be meaningless
+#[allow(clippy::len_without_is_empty)]
+impl<V: Vectorable> SparseVector<V> {
+    /// Creates a new [`SparseVector`] from `(index, value)` pairs in the range from `start` to `end`.
+    ///
+    /// The pairs do not need to be sorted.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - `start` > `end`.
+    /// * [`VectorError::Overflow`] - The length of the range does not fit into a [`usize`].
+    ///   `start` = 0 and `end` = [`usize::MAX`].
+    /// * [`VectorError::Indexing`] - The index of a pair is outside of the range.
+    ///   `index` < `start` or `index` > `end`.
+    /// * [`VectorError::Argument`] - `pairs` contains an index more than once.
+    pub fn from_pairs(
+        mut pairs: Vec<(usize, V)>,
+        start: usize,
+        end: usize,
+        fallback: V,
+    ) -> Result<Self, VectorError> {
+        VectorError::len_from_range(start, end)?;
+
+        if let Some(&(index, _)) = pairs
+            .iter()
+            .find(|(index, _)| *index < start || *index > end)
+        {
+            return Err(VectorError::Indexing { index });
+        }
+
+        pairs.sort_unstable_by_key(|&(index, _)| index);
+        if pairs.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(VectorError::Argument { name: "pairs" });
+        }
+
+        Ok(Self {
+            pairs,
+            start,
+            end,
+            fallback,
+        })
+    }
+
+    /// Returns the `start` index of the declared range.
+    #[inline]
+    #[must_use]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the `end` index of the declared range.
+    ///
+    /// Note that the end is included.
+    #[inline]
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the length of the declared range.
+    ///
+    /// This is equal to `end` - `start` + 1 and includes absent indices.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.end - self.start + 1
+    }
+
+    /// Returns the number of present elements.
+    #[inline]
+    #[must_use]
+    pub fn count_present(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns the value returned for absent indices.
+    #[inline]
+    #[must_use]
+    pub fn fallback(&self) -> V {
+        self.fallback
+    }
+
+    /// Returns an iterator over the present `(index, value)` pairs sorted by their absolute index.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, (usize, V)> {
+        self.pairs.iter()
+    }
+
+    /// Returns the value at the absolute `index`.
+    ///
+    /// If no element is present at `index` the `fallback` is returned.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - `index` is outside of the declared range.
+    ///   `index` < `start` or `index` > `end`.
+    pub fn get(&self, index: usize) -> Result<V, VectorError> {
+        if index < self.start || index > self.end {
+            return Err(VectorError::Indexing { index });
+        }
+
+        Ok(self
+            .pairs
+            .binary_search_by_key(&index, |&(index, _)| index)
+            .map_or(self.fallback, |position| self.pairs[position].1))
+    }
+
+    /// Converts the [`SparseVector`] into a dense [`OwnedVector`] with the same `start` and `end`.
+    ///
+    /// All absent indices are set to `fill`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{SparseVector, Vector};
+    /// let sparse: SparseVector<i32> = SparseVector::from_pairs(vec![(14, 2), (10, 1)], 10, 15, 0).unwrap();
+    /// assert_eq!(sparse.get(12), Ok(0));
+    ///
+    /// let dense = sparse.densify(-1);
+    /// assert_eq!((dense.start(), dense.end()), (10, 15));
+    /// assert_eq!(dense.as_slice(), &[1, -1, -1, -1, 2, -1]);
+    /// ```
+    #[must_use]
+    pub fn densify(&self, fill: V) -> OwnedVector<V> {
+        // Safe, because `start` <= `end` per construction
+        let mut vector: OwnedVector<V> = OwnedVector::from_num(fill, self.start, self.end).unwrap();
+
+        for &(index, value) in &self.pairs {
+            vector[index] = value;
+        }
+
+        vector
+    }
+}
@@ -0,0 +1,54 @@
+#[allow(unused_imports)]
+use crate::OwnedVector;
+use crate::Vectorable;
+
+/// Helper trait for the stepped construction of [`OwnedVector::arange`].
+///
+/// This trait is automatically implemented for all basic numeric types.
+pub trait Steppable: Vectorable + PartialOrd {
+    /// The neutral element of the addition.
+    const ZERO: Self;
+
+    /// Adds `step` to the value, returning [`None`] on overflow.
+    ///
+    /// Floating point types never overflow, but a tiny `step` might not change the value.
+    #[must_use]
+    fn checked_step(self, step: Self) -> Option<Self>;
+}
+
+/// Implements [`Steppable`] for integers by forwarding to the checked addition of the primitive.
+macro_rules! impl_steppable_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Steppable for $t {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn checked_step(self, step: Self) -> Option<Self> {
+                    <$t>::checked_add(self, step)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`Steppable`] for floating point types using the plain addition.
+macro_rules! impl_steppable_float {
+    ($($t:ty),*) => {
+        $(
+            impl Steppable for $t {
+                const ZERO: Self = 0.0;
+
+                #[inline]
+                fn checked_step(self, step: Self) -> Option<Self> {
+                    Some(self + step)
+                }
+            }
+        )*
+    };
+}
+
+impl_steppable_integer!(
+    i128, i64, i32, i16, i8, isize, u128, u64, u32, u16, u8, usize
+);
+impl_steppable_float!(f64, f32);
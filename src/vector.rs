@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     ops::{Index, Range, RangeInclusive},
     slice::Iter,
 };
@@ -9,6 +10,8 @@ use crate::{VectorError, Vectorable};
 use crate::{BorrowedVector, OwnedVector};
 
 /// A trait to combine the usage [`OwnedVector`] and [`BorrowedVector`].
+// A `Vector` always holds at least one element, so an `is_empty` would be meaningless
+#[allow(clippy::len_without_is_empty)]
 pub trait Vector<V: Vectorable>:
     Index<usize, Output = V>
     + Index<RangeInclusive<usize>, Output = [V]>
@@ -27,6 +30,8 @@ pub trait Vector<V: Vectorable>:
     fn end(&self) -> usize;
 
     /// Extracts a slice containing the entire vector.
+    ///
+    /// This exposes the underlying storage, where position 0 corresponds to `start`.
     #[must_use]
     fn as_slice(&self) -> &[V];
 
@@ -35,9 +40,9 @@ pub trait Vector<V: Vectorable>:
     /// # Errors
     ///
     /// * [`VectorError::Indexing`] - If `start` or `end` are out of bounds of the current vector.
-    /// `start` < `self.start` or `end` > `self.end`.
+    ///   `start` < `self.start` or `end` > `self.end`.
     /// * [`VectorError::Order`] - The order of the arguments is wrong.
-    /// `start` > `end`.
+    ///   `start` > `end`.
     fn slice(&'_ self, start: usize, end: usize) -> Result<BorrowedVector<'_, V>, VectorError>;
 
     /// Returns an iterator over the vector.
@@ -47,6 +52,93 @@ pub trait Vector<V: Vectorable>:
         self.as_slice().iter()
     }
 
+    /// Returns an iterator over all elements together with their absolute index.
+    ///
+    /// Each item is `(index, &self[index])`.
+    fn iter_indexed<'a>(&'a self) -> impl Iterator<Item = (usize, &'a V)>
+    where
+        V: 'a,
+    {
+        let start: usize = self.start();
+
+        self.iter()
+            .enumerate()
+            .map(move |(offset, value)| (start + offset, value))
+    }
+
+    /// Returns an iterator over all elements together with their absolute index from `end` down to `start`.
+    ///
+    /// Each item is `(index, &self[index])`.
+    fn iter_indexed_rev<'a>(&'a self) -> impl Iterator<Item = (usize, &'a V)>
+    where
+        V: 'a,
+    {
+        let end: usize = self.end();
+
+        self.iter()
+            .rev()
+            .enumerate()
+            .map(move |(offset, value)| (end - offset, value))
+    }
+
+    /// Folds all elements into an accumulator, passing the absolute index alongside each value.
+    ///
+    /// This allows index-dependent reductions like moments.
+    fn fold_indexed<B, F: FnMut(B, usize, &V) -> B>(&self, init: B, mut function: F) -> B {
+        self.iter_indexed()
+            .fold(init, |accumulator, (index, value)| {
+                function(accumulator, index, value)
+            })
+    }
+
+    /// Returns an iterator over all pairs of adjacent elements.
+    ///
+    /// Each item is `(index, &self[index], &self[index + 1])`, where `index` is the absolute index of the left element.
+    /// A single element vector yields no pairs.
+    fn pairs<'a>(&'a self) -> impl Iterator<Item = (usize, &'a V, &'a V)>
+    where
+        V: 'a,
+    {
+        let start: usize = self.start();
+
+        self.as_slice()
+            .windows(2)
+            .enumerate()
+            .map(move |(offset, pair)| (start + offset, &pair[0], &pair[1]))
+    }
+
+    /// Returns a reference to the element at `start`.
+    ///
+    /// As a [`Vector`] always holds at least one element, this only returns [`None`] for an empty underlying slice.
+    #[must_use]
+    fn first(&self) -> Option<&V> {
+        self.as_slice().first()
+    }
+
+    /// Returns a reference to the element at `end`.
+    ///
+    /// As a [`Vector`] always holds at least one element, this only returns [`None`] for an empty underlying slice.
+    #[must_use]
+    fn last(&self) -> Option<&V> {
+        self.as_slice().last()
+    }
+
+    /// Returns the absolute index of the first element.
+    ///
+    /// This is equal to `start`.
+    #[must_use]
+    fn first_index(&self) -> usize {
+        self.start()
+    }
+
+    /// Returns the absolute index of the last element.
+    ///
+    /// This is equal to `end`.
+    #[must_use]
+    fn last_index(&self) -> usize {
+        self.end()
+    }
+
     /// Returns the length of the underlying vector.
     ///
     /// Per construction this is equal to `end` - `start` + 1.
@@ -61,9 +153,51 @@ pub trait Vector<V: Vectorable>:
     /// # Errors
     ///
     /// * [`VectorError::Indexing`] - The underlying vector does not have enough elements.
-    /// `index` < `start` or `index` > `end`.
+    ///   `index` < `start` or `index` > `end`.
     fn get(&self, index: usize) -> Result<V, VectorError>;
 
+    /// Returns a reference to the element at the `index`th position using the offset indexing system.
+    ///
+    /// In contrast to indexing this never returns a fallback value of an [`OwnedVector`].
+    /// [`None`] is returned if `index` < `start` or `index` > `end`, so genuine elements can be distinguished from fallbacks.
+    #[must_use]
+    fn get_ref(&self, index: usize) -> Option<&V> {
+        // Underflow will wrap around and return a `None` variant
+        self.as_slice().get(index.wrapping_sub(self.start()))
+    }
+
+    /// Returns a reference to the element at the `index`th position using the offset indexing system.
+    ///
+    /// In contrast to [`Vector::get`] the element is not copied and in contrast to indexing this does not panic.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - There is no element at `index`.
+    ///   `index` < `start` or `index` > `end`.
+    fn try_index(&self, index: usize) -> Result<&V, VectorError> {
+        self.get_ref(index).ok_or(VectorError::Indexing { index })
+    }
+
+    /// Returns the value at the `index`th position using the offset indexing system or `default` if `index` is out of range.
+    ///
+    /// In contrast to [`Vector::get`] this never fails.
+    #[must_use]
+    fn get_or(&self, index: usize, default: V) -> V {
+        self.get(index).unwrap_or(default)
+    }
+
+    /// Returns the values at all `indices` using the offset indexing system.
+    ///
+    /// The values are returned in the order of `indices`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Indexing`] - The first index outside of the vector.
+    ///   `index` < `start` or `index` > `end`.
+    fn get_many(&self, indices: &[usize]) -> Result<Vec<V>, VectorError> {
+        indices.iter().map(|&index| self.get(index)).collect()
+    }
+
     /// Returns the value at the `index`th position using the original indexing system.
     ///
     /// This ignores the offest.
@@ -72,7 +206,7 @@ pub trait Vector<V: Vectorable>:
     /// # Errors
     ///
     /// * [`VectorError::Indexing`] - The underlying vector does not have enough elements.
-    /// `vec.len()` - 1 < `index`.
+    ///   `vec.len()` - 1 < `index`.
     fn get_absolute(&self, index: usize) -> Result<V, VectorError>;
 
     /// Returns a slice inside the underlying vector based on the offset range from `start` to `end`.
@@ -80,7 +214,7 @@ pub trait Vector<V: Vectorable>:
     /// # Errors
     ///
     /// * [`VectorError::Indexing`] - The `start` or `end` is outside the supported range.
-    /// `start` < `self.start` or `end` > `self.end` + 1.
+    ///   `start` < `self.start` or `end` > `self.end` + 1.
     fn get_range(&self, start: usize, end: usize) -> Result<&[V], VectorError>;
 
     /// Returns a slice inside the underlying vector based on the offset range from `start` to `end`.
@@ -88,9 +222,98 @@ pub trait Vector<V: Vectorable>:
     /// # Errors
     ///
     /// * [`VectorError::Indexing`] - The `start` or `end` is outside the supported range.
-    /// `start` < `self.start` or `end` > `self.end` + 1.
+    ///   `start` < `self.start` or `end` > `self.end` + 1.
     fn get_range_inclusive(&self, start: usize, end: usize) -> Result<&[V], VectorError>;
 
+    /// Returns the absolute index of the first element for which `function` returns `true`.
+    ///
+    /// In contrast to [`Iterator::position`] the `start` is already added.
+    #[must_use]
+    fn position<F: FnMut(&V) -> bool>(&self, function: F) -> Option<usize> {
+        self.iter()
+            .position(function)
+            .map(|offset| self.start() + offset)
+    }
+
+    /// Returns a reference to the first element for which `function` returns `true`.
+    #[must_use]
+    fn find<F: FnMut(&V) -> bool>(&self, mut function: F) -> Option<&V> {
+        self.iter().find(|value| function(value))
+    }
+
+    /// Checks whether the vector contains an element equal to `value`.
+    #[must_use]
+    fn contains(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.as_slice().contains(value)
+    }
+
+    /// Returns the number of elements for which `function` returns `true`.
+    #[must_use]
+    fn count<F: FnMut(&V) -> bool>(&self, mut function: F) -> usize {
+        self.iter().filter(|value| function(value)).count()
+    }
+
+    /// Returns the absolute index of the minimum element.
+    ///
+    /// Ties resolve to the first occurrence.
+    /// Elements which are not comparable to themselves, like `NaN`, are skipped.
+    /// If no element is comparable [`None`] is returned.
+    #[must_use]
+    fn argmin(&self) -> Option<usize>
+    where
+        V: PartialOrd,
+    {
+        arg_extremum(self.as_slice(), self.start(), |value, best| value < best)
+    }
+
+    /// Returns the absolute index of the maximum element.
+    ///
+    /// Ties resolve to the first occurrence.
+    /// Elements which are not comparable to themselves, like `NaN`, are skipped.
+    /// If no element is comparable [`None`] is returned.
+    #[must_use]
+    fn argmax(&self) -> Option<usize>
+    where
+        V: PartialOrd,
+    {
+        arg_extremum(self.as_slice(), self.start(), |value, best| value > best)
+    }
+
+    /// Binary searches the sorted vector for `target` using the offset indexing system.
+    ///
+    /// If the value is found [`Ok`] with the absolute index of a matching element is returned.
+    /// Otherwise [`Err`] with the absolute index where `target` could be inserted while maintaining the order is returned.
+    ///
+    /// # Errors
+    ///
+    /// * The absolute insertion index, if `target` is not found.
+    fn binary_search(&self, target: &V) -> Result<usize, usize>
+    where
+        V: Ord,
+    {
+        self.binary_search_by(|value| value.cmp(target))
+    }
+
+    /// Binary searches the sorted vector with a comparator function using the offset indexing system.
+    ///
+    /// The comparator returns the [`Ordering`] of the element compared to the target.
+    /// For floating point types this allows the usage of `total_cmp`.
+    ///
+    /// # Errors
+    ///
+    /// * The absolute insertion index, if no matching element is found.
+    fn binary_search_by<F: FnMut(&V) -> Ordering>(&self, function: F) -> Result<usize, usize> {
+        let start: usize = self.start();
+
+        self.as_slice()
+            .binary_search_by(function)
+            .map(|offset| start + offset)
+            .map_err(|offset| start + offset)
+    }
+
     /// Checks whether two [`Vector`]s are compatible.
     ///
     /// This can be used before a [`Iter::zip`] to assert that no values are lost.
@@ -112,4 +335,137 @@ pub trait Vector<V: Vectorable>:
             })
         }
     }
+
+    /// Checks whether `index` lies inside the vector using the offset indexing system.
+    ///
+    /// This is `start` <= `index` <= `end`.
+    #[must_use]
+    fn contains_index(&self, index: usize) -> bool {
+        self.start() <= index && index <= self.end()
+    }
+
+    /// Returns all absolute indices from `full_start` to `full_end` which are not covered by the vector.
+    ///
+    /// If `full_start` > `full_end` no indices are returned.
+    #[must_use]
+    fn missing_indices(&self, full_start: usize, full_end: usize) -> Vec<usize> {
+        (full_start..=full_end)
+            .filter(|&index| !self.contains_index(index))
+            .collect()
+    }
+
+    /// Checks whether the ranges of two [`Vector`]s share at least one index.
+    ///
+    /// Only `start` and `end` are considered, so the element types may differ.
+    #[must_use]
+    fn overlaps<W: Vectorable>(&self, other: &impl Vector<W>) -> bool {
+        self.start() <= other.end() && other.start() <= self.end()
+    }
+
+    /// Returns the `start` and `end` of the range shared by two [`Vector`]s.
+    ///
+    /// If the ranges do not overlap [`None`] is returned.
+    #[must_use]
+    fn intersection<W: Vectorable>(&self, other: &impl Vector<W>) -> Option<(usize, usize)> {
+        if self.overlaps(other) {
+            Some((self.start().max(other.start()), self.end().min(other.end())))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `start` and `end` of the smallest range containing both [`Vector`]s.
+    ///
+    /// The ranges do not need to overlap, so the hull can contain indices of neither vector.
+    #[must_use]
+    fn union_hull<W: Vectorable>(&self, other: &impl Vector<W>) -> (usize, usize) {
+        (self.start().min(other.start()), self.end().max(other.end()))
+    }
+}
+
+/// Zips three [`Vector`]s with the same range into an iterator of `(index, &a[index], &b[index], &c[index])`.
+///
+/// The `index` is the absolute index.
+///
+/// # Errors
+///
+/// * [`VectorError::Compatibility`] - The `start` or `end` of `b` or `c` do not match the ones of `a`.
+pub fn zip3<'a, V: Vectorable + 'a>(
+    a: &'a impl Vector<V>,
+    b: &'a impl Vector<V>,
+    c: &'a impl Vector<V>,
+) -> Result<impl Iterator<Item = (usize, &'a V, &'a V, &'a V)>, VectorError> {
+    a.compatible(b)?;
+    a.compatible(c)?;
+
+    Ok(a.iter_indexed()
+        .zip(b.iter())
+        .zip(c.iter())
+        .map(|(((index, a), b), c)| (index, a, b, c)))
+}
+
+/// Returns the absolute index of the first element, which is `better` than all previous ones.
+///
+/// Helper for [`Vector::argmin`] and [`Vector::argmax`].
+fn arg_extremum<V: PartialOrd, F: FnMut(&V, &V) -> bool>(
+    values: &[V],
+    start: usize,
+    mut better: F,
+) -> Option<usize> {
+    let mut best: Option<(usize, &V)> = None;
+
+    for (offset, value) in values.iter().enumerate() {
+        // Skip values like `NaN`
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+
+        match best {
+            Some((_, best_value)) if !better(value, best_value) => {}
+            _ => best = Some((offset, value)),
+        }
+    }
+
+    best.map(|(offset, _)| start + offset)
+}
+
+/// Returns the position of the absolute `index` in the underlying storage of a vector ranging from `start` to `end`.
+///
+/// Helper for the [`Index`] implementations.
+///
+/// # Panics
+///
+/// * `index` < `start` or `index` > `end`.
+///   The message names the violated bound.
+#[inline]
+#[track_caller]
+pub(crate) fn index_offset(index: usize, start: usize, end: usize) -> usize {
+    if index < start {
+        panic!("index {} is below start {}", index, start);
+    } else if index > end {
+        panic!("index {} is above end {}", index, end);
+    }
+
+    index - start
+}
+
+/// Returns the position of the exclusive bound `index` in the underlying storage of a vector ranging from `start` to `end`.
+///
+/// In contrast to [`index_offset`] the `index` may lie one past `end`.
+/// Helper for the [`Index`] implementations of [`Range`](std::ops::Range).
+///
+/// # Panics
+///
+/// * `index` < `start` or `index` > `end` + 1.
+///   The message names the violated bound.
+#[inline]
+#[track_caller]
+pub(crate) fn exclusive_offset(index: usize, start: usize, end: usize) -> usize {
+    if index < start {
+        panic!("index {} is below start {}", index, start);
+    } else if index - start > 0 && index - start - 1 > end - start {
+        panic!("index {} is above end {}", index, end);
+    }
+
+    index - start
 }
@@ -0,0 +1,82 @@
+use crate::{OwnedVector, VectorError, Vectorable};
+
+/// A builder for the incremental construction of an [`OwnedVector`] with a known `start`.
+///
+/// The `end` is calculated from the number of pushed values when building.
+///
+/// The generic value needs to implement the [`Vectorable`] trait.
+#[derive(Debug, Clone)]
+pub struct VectorBuilder<V: Vectorable> {
+    /// The values pushed so far.
+    vector: Vec<V>,
+
+    /// The start of the resulting vector.
+    start: usize,
+}
+
+impl<V: Vectorable> VectorBuilder<V> {
+    /// Creates a new empty [`VectorBuilder`] for a vector beginning at `start`.
+    #[must_use]
+    pub fn new(start: usize) -> Self {
+        Self {
+            vector: Vec::new(),
+            start,
+        }
+    }
+
+    /// Creates a new empty [`VectorBuilder`] for a vector beginning at `start` with room for `capacity` values.
+    #[must_use]
+    pub fn with_capacity(start: usize, capacity: usize) -> Self {
+        Self {
+            vector: Vec::with_capacity(capacity),
+            start,
+        }
+    }
+
+    /// Appends `value` at the next absolute index.
+    pub fn push(&mut self, value: V) {
+        self.vector.push(value);
+    }
+
+    /// Returns the number of values pushed so far.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vector.len()
+    }
+
+    /// Returns `true` if no value has been pushed yet.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vector.is_empty()
+    }
+
+    /// Builds the [`OwnedVector`] with `end` = `start` + `len` - 1.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - No value has been pushed.
+    /// * [`VectorError::Overflow`] - The `end` does not fit into a [`usize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use vector::{Vector, VectorBuilder, VectorError};
+    /// let mut builder: VectorBuilder<u8> = VectorBuilder::new(7);
+    /// builder.push(1);
+    /// builder.push(2);
+    /// builder.push(3);
+    ///
+    /// let vec = builder.build().unwrap();
+    /// assert_eq!((vec.start(), vec.end()), (7, 9));
+    ///
+    /// let error = VectorBuilder::<u8>::new(7).build().unwrap_err();
+    /// assert_eq!(error, VectorError::Empty);
+    /// ```
+    pub fn build(self) -> Result<OwnedVector<V>, VectorError> {
+        let end: usize = VectorError::end_from_len(self.start, self.vector.len())?;
+
+        OwnedVector::from_vec(self.vector, self.start, end)
+    }
+}
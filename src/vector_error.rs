@@ -3,11 +3,14 @@ use std::{
     fmt::{Display, Formatter},
 };
 
+use crate::question_mark;
+
 #[allow(unused_imports)]
-use crate::vector::Vector;
+use crate::{IsizeVector, vector::Vector};
 
 /// An enum for handling the errors involved in the creation and access of [`Vector`] instances.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum VectorError {
     /// Start and end are not ordered correctly.
     ///
@@ -40,6 +43,37 @@ pub enum VectorError {
         end_1: usize,
         end_2: usize,
     },
+
+    /// An argument is outside of its supported domain.
+    ///
+    /// `name` is the name of the offending argument.
+    Argument { name: &'static str },
+
+    /// The vector would not contain any elements.
+    ///
+    /// A vector always holds at least the element at `start`.
+    Empty,
+
+    /// The `end` of a vector does not fit into a [`usize`].
+    ///
+    /// `start` + `len` - 1 > [`usize::MAX`].
+    /// If the length of a range itself does not fit into a [`usize`], `len` saturates at [`usize::MAX`].
+    Overflow { start: usize, len: usize },
+
+    /// There is no element at this signed index.
+    ///
+    /// This is the [`VectorError::Indexing`] equivalent for an [`IsizeVector`].
+    SignedIndexing { index: isize },
+
+    /// The `end` of an [`IsizeVector`] does not fit into an [`isize`].
+    ///
+    /// `start` + `len` - 1 > [`isize::MAX`].
+    SignedOverflow { start: isize, len: usize },
+
+    /// An arithmetic operation overflowed.
+    ///
+    /// `index` is the absolute index of the first overflowing element.
+    Arithmetic { index: usize },
 }
 
 impl VectorError {
@@ -65,13 +99,53 @@ impl VectorError {
     #[inline]
     pub(crate) const fn check_len(len: usize, start: usize, end: usize) -> Result<(), VectorError> {
         // Equivalent to len == end - start + 1
-        // Protects from underflow
-        if len + start == end + 1 {
+        // Protects from underflow and overflow
+        if len > 0 && start <= end && len - 1 == end - start {
             Ok(())
         } else {
             Err(VectorError::Length { len, start, end })
         }
     }
+
+    /// Helper to calculate the length of a vector ranging from `start` to `end`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Order`] - `start` > `end`.
+    /// * [`VectorError::Overflow`] - The length `end` - `start` + 1 does not fit into a [`usize`].
+    ///   The reported `len` saturates at [`usize::MAX`].
+    #[inline]
+    pub(crate) const fn len_from_range(start: usize, end: usize) -> Result<usize, VectorError> {
+        question_mark!(Self::check_order(start, end));
+
+        // `end` - `start` is safe, because `check_order` passed
+        match (end - start).checked_add(1) {
+            Some(len) => Ok(len),
+            None => Err(VectorError::Overflow {
+                start,
+                len: usize::MAX,
+            }),
+        }
+    }
+
+    /// Helper to calculate the `end` of a vector with `len` elements beginning at `start`.
+    ///
+    /// # Errors
+    ///
+    /// * [`VectorError::Empty`] - `len` == 0.
+    /// * [`VectorError::Overflow`] - `start` + `len` - 1 > [`usize::MAX`].
+    #[inline]
+    pub(crate) const fn end_from_len(start: usize, len: usize) -> Result<usize, VectorError> {
+        if len == 0 {
+            return Err(VectorError::Empty);
+        }
+
+        // `len` - 1 is safe, because `len` > 0
+        match start.checked_add(len - 1) {
+            Some(end) => Ok(end),
+            None => Err(VectorError::Overflow { start, len }),
+        }
+    }
 }
 
 impl Display for VectorError {
@@ -98,6 +172,30 @@ impl Display for VectorError {
                 "Either the starts ({} vs. {}) do not match or the ends ({} vs {})",
                 start_1, start_2, end_1, end_2
             ),
+            Self::Argument { name } => write!(
+                format,
+                "The argument `{}` is outside of its supported domain",
+                name
+            ),
+            Self::Empty => write!(format, "The vector would not contain any elements"),
+            Self::Overflow { start, len } => write!(
+                format,
+                "The end of a vector starting at {} with {} elements exceeds the maximum index",
+                start, len
+            ),
+            Self::SignedIndexing { index } => {
+                write!(format, "No element at position {} exists", index)
+            }
+            Self::SignedOverflow { start, len } => write!(
+                format,
+                "The end of a vector starting at {} with {} elements exceeds the maximum index",
+                start, len
+            ),
+            Self::Arithmetic { index } => write!(
+                format,
+                "The arithmetic operation overflowed at position {}",
+                index
+            ),
         }
     }
 }
@@ -8,6 +8,7 @@ use crate::Vector;
 /// No special requirements except [`Copy`] and [`Debug`].
 ///
 /// This trait is automatically implemented for all basic numeric types.
+/// It is also implemented for [`bool`] to allow masks.
 pub trait Vectorable: Copy + Debug {}
 
 impl Vectorable for f64 {}
@@ -24,3 +25,4 @@ impl Vectorable for u32 {}
 impl Vectorable for u16 {}
 impl Vectorable for u8 {}
 impl Vectorable for usize {}
+impl Vectorable for bool {}